@@ -0,0 +1,179 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use strum::IntoEnumIterator;
+use crate::coord_grid::{Direction, Grid};
+use crate::error::AocError;
+use crate::simulation::{self, Cycle, Simulation};
+
+// The cells whose values are handed to the rule, as (row, column) offsets from the cell being updated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighbourhood {
+    offsets: Vec<(isize, isize)>,
+}
+
+impl Neighbourhood {
+    pub fn from_directions(directions: &[Direction]) -> Self {
+        Self::from_offsets(directions.iter().map(Direction::offset).collect())
+    }
+
+    pub fn from_offsets(offsets: Vec<(isize, isize)>) -> Self {
+        Self { offsets }
+    }
+
+    // the 4 cells sharing an edge with the cell
+    pub fn orthogonal() -> Self {
+        Self::from_directions(&Direction::main_directions())
+    }
+
+    // the 8 cells sharing an edge or a corner with the cell
+    pub fn all_directions() -> Self {
+        Self::from_directions(&Direction::iter().collect::<Vec<_>>())
+    }
+
+    fn neighbours(&self, (i, j): (usize, usize), width: usize, height: usize) -> impl Iterator<Item=(usize, usize)> + '_ {
+        self.offsets.iter().filter_map(move |&(di, dj)| {
+            let (i, j) = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+            (i < height && j < width).then_some((i, j))
+        })
+    }
+}
+
+// Updates every cell of a grid at once, from its value and those of its neighbours (cells outside the grid are left
+// out). Generations are computed into a second grid, which is then swapped with the current one.
+#[derive(Clone)]
+pub struct Automaton<T: Copy + Debug + Default + PartialEq, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    neighbourhood: Neighbourhood,
+    rule: R,
+    generation: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Copy + Debug + Default + PartialEq + Send + Sync,
+    R: Fn(T, &[T]) -> T + Sync,
+{
+    pub fn new(grid: Grid<T>, neighbourhood: Neighbourhood, rule: R) -> Self {
+        Self { next: grid.clone(), current: grid, neighbourhood, rule, generation: 0 }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn next_row(&self, i: usize, row: &mut [T]) {
+        let (width, height) = (self.current.width(), self.current.height());
+        let mut neighbours = Vec::with_capacity(self.neighbourhood.offsets.len());
+        for (j, cell) in row.iter_mut().enumerate() {
+            neighbours.clear();
+            neighbours.extend(self.neighbourhood.neighbours((i, j), width, height).map(|c| self.current.get(c).unwrap()));
+            *cell = (self.rule)(self.current.get((i, j)).unwrap(), &neighbours);
+        }
+    }
+
+    // Computes the next generation (with the `parallel` feature, rows are computed concurrently), returning whether
+    // any cell changed
+    pub fn step(&mut self) -> bool {
+        let mut next = std::mem::replace(&mut self.next, Grid::create(0, 0));
+        #[cfg(feature = "parallel")]
+        next.raw_mut().par_iter_mut().enumerate().for_each(|(i, row)| self.next_row(i, row));
+        #[cfg(not(feature = "parallel"))]
+        next.raw_mut().iter_mut().enumerate().for_each(|(i, row)| self.next_row(i, row));
+        let changed = next.raw() != self.current.raw();
+        self.next = std::mem::replace(&mut self.current, next);
+        self.generation += 1;
+        changed
+    }
+
+    // Steps until a generation doesn't change anything, returning the generation reached, or `None` if it is still
+    // changing after `max_generations`
+    pub fn run_until_stable(&mut self, max_generations: usize) -> Option<usize> {
+        for _ in 0..max_generations {
+            if !self.step() { return Some(self.generation); }
+        }
+        None
+    }
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Copy + Debug + Default + Eq + Hash + Send + Sync,
+    R: Fn(T, &[T]) -> T + Sync + Clone,
+{
+    // Finds the generations that repeat from the current one on - a stable state is a cycle with a period of 1
+    pub fn find_cycle(&self) -> Result<Cycle, AocError> {
+        // the grid has finitely many states, so the automaton always ends up in a cycle
+        Ok(simulation::find_cycle(self)?.expect("automata don't finish"))
+    }
+}
+
+impl<T, R> Simulation for Automaton<T, R>
+where
+    T: Copy + Debug + Default + Eq + Hash + Send + Sync,
+    R: Fn(T, &[T]) -> T + Sync + Clone,
+{
+    type Key = Vec<Vec<T>>;
+
+    fn step(&mut self) {
+        Automaton::step(self);
+    }
+
+    fn state_key(&self) -> Self::Key {
+        self.current.raw().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord_grid::str_to_grid;
+
+    fn life(input: &str) -> Automaton<bool, impl Fn(bool, &[bool]) -> bool + Sync + Clone> {
+        let grid = str_to_grid(input, |c| Ok(c == '#')).unwrap();
+        Automaton::new(grid, Neighbourhood::all_directions(), |alive, neighbours: &[bool]| {
+            matches!((alive, neighbours.iter().filter(|&&n| n).count()), (true, 2) | (_, 3))
+        })
+    }
+
+    fn render(grid: &Grid<bool>) -> Vec<String> {
+        grid.iter_rows().map(|row| row.iter().map(|&a| if a { '#' } else { '.' }).collect()).collect()
+    }
+
+    #[test]
+    fn blinker() {
+        let mut automaton = life(".....\n..#..\n..#..\n..#..\n.....");
+        assert!(automaton.step());
+        assert_eq!(render(automaton.grid()), [".....", ".....", ".###.", ".....", "....."]);
+        assert_eq!(automaton.generation(), 1);
+        assert_eq!(automaton.find_cycle(), Ok(Cycle { start: 0, period: 2 }));
+        assert_eq!(automaton.run_until_stable(10), None);
+    }
+
+    #[test]
+    fn stable() {
+        // a lone cell dies, leaving the block behind
+        let mut automaton = life("#.....\n......\n...##.\n...##.\n......");
+        assert_eq!(automaton.find_cycle(), Ok(Cycle { start: 1, period: 1 }));
+        assert_eq!(automaton.run_until_stable(10), Some(2));
+        assert_eq!(render(automaton.grid()), ["......", "......", "...##.", "...##.", "......"]);
+    }
+
+    #[test]
+    fn custom_neighbourhood() {
+        // every cell takes the value of the cell two columns to its left, if there is one
+        let grid = str_to_grid("1234", |c| c.to_digit(10).ok_or(String::from("not a digit"))).unwrap();
+        let mut automaton = Automaton::new(grid, Neighbourhood::from_offsets(vec![(0, -2)]), |cell, neighbours: &[u32]| {
+            neighbours.first().copied().unwrap_or(cell)
+        });
+        automaton.step();
+        assert_eq!(automaton.grid().raw(), [vec![1, 2, 1, 2]]);
+        assert_eq!(Neighbourhood::orthogonal().neighbours((0, 0), 3, 3).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::time::{Duration, Instant};
+#[cfg(feature = "alloc-stats")]
+use aoc_2024_rust::alloc_stats::{self, AllocStats};
+use aoc_2024_rust::cancel::{self, CancellationToken};
+use aoc_2024_rust::error::AocError;
+use aoc_2024_rust::solution::{DynSolution, Part};
+
+pub const BENCH_FILE: &str = "bench_results.txt";
+pub const DEFAULT_RUNS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+pub fn timed_out(budget: Duration, token: &CancellationToken) -> AocError {
+    let progress = token.last_progress().map(|p| format!(", last progress: {p}")).unwrap_or_default();
+    AocError::new(format!("timed out after {}{progress}", format_duration(budget)))
+}
+
+// Runs `f` within the budget, which a part running out of makes the whole benchmark fail
+fn within_budget<T>(budget: Option<Duration>, part: Part, f: impl FnOnce() -> T) -> Result<T, AocError> {
+    let (result, token) = cancel::with_budget(budget, f);
+    match budget {
+        Some(budget) if token.is_cancelled() => Err(AocError::new(format!("part {part} {}", timed_out(budget, &token)))),
+        _ => Ok(result),
+    }
+}
+
+// Results of the parts are ignored, so that unimplemented parts simply show up as (almost) zero. The budget applies
+// to each run of a part, with the timing taken inside it so that starting the watchdog isn't measured.
+pub fn bench_day(solution: &dyn DynSolution, input: &str, runs: usize, budget: Option<Duration>) -> Result<Timings, AocError> {
+    let (mut parse, mut part1, mut part2) = (Vec::new(), Vec::new(), Vec::new());
+    for _ in 0..runs.max(1) {
+        let (parsed, t) = time(|| solution.parse_input(input));
+        let parsed = parsed?;
+        parse.push(t);
+        part1.push(within_budget(budget, Part::One, || time(|| solution.solve(parsed.as_ref(), Part::One)).1)?);
+        part2.push(within_budget(budget, Part::Two, || time(|| solution.solve(parsed.as_ref(), Part::Two)).1)?);
+    }
+    Ok(Timings { parse: median(parse), part1: median(part1), part2: median(part2) })
+}
+
+// Allocations of parsing and both parts, measured in a run of their own after the timed ones
+#[cfg(feature = "alloc-stats")]
+pub fn measure_allocations(solution: &dyn DynSolution, input: &str, budget: Option<Duration>) -> Result<[AllocStats; 3], AocError> {
+    let (parsed, parse) = alloc_stats::measure(|| solution.parse_input(input));
+    let parsed = parsed?;
+    let part1 = within_budget(budget, Part::One, || alloc_stats::measure(|| solution.solve(parsed.as_ref(), Part::One)).1)?;
+    let part2 = within_budget(budget, Part::Two, || alloc_stats::measure(|| solution.solve(parsed.as_ref(), Part::Two)).1)?;
+    Ok([parse, part1, part2])
+}
+
+// One line per day: `<day> <parse> <part 1> <part 2>`, tab-separated, in nanoseconds
+pub fn load_history(path: &str) -> Result<BTreeMap<u8, Timings>, AocError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => return Err(AocError::new(format!("unable to read {path}: {e}"))),
+    };
+    let mut history = BTreeMap::new();
+    for line in contents.lines() {
+        if line.trim().is_empty() { continue; }
+        let invalid = || AocError::at(&contents, line, "invalid benchmark entry");
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [day, parse, part1, part2] = fields[..] else { return Err(invalid()); };
+        let nanos = |s: &str| s.parse::<u64>().map(Duration::from_nanos).map_err(|_| invalid());
+        history.insert(
+            day.parse().map_err(|_| invalid())?,
+            Timings { parse: nanos(parse)?, part1: nanos(part1)?, part2: nanos(part2)? },
+        );
+    }
+    Ok(history)
+}
+
+pub fn save_history(path: &str, history: &BTreeMap<u8, Timings>) -> Result<(), AocError> {
+    let contents: String = history.iter()
+        .map(|(day, t)| format!("{day}\t{}\t{}\t{}\n", t.parse.as_nanos(), t.part1.as_nanos(), t.part2.as_nanos()))
+        .collect();
+    fs::write(path, contents).map_err(|e| AocError::new(format!("unable to write {path}: {e}")))
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+fn format_bytes(bytes: usize) -> String {
+    if bytes < 1 << 10 {
+        format!("{bytes} B")
+    } else if bytes < 1 << 20 {
+        format!("{:.1} KiB", bytes as f64 / f64::from(1 << 10))
+    } else if bytes < 1 << 30 {
+        format!("{:.1} MiB", bytes as f64 / f64::from(1 << 20))
+    } else {
+        format!("{:.2} GiB", bytes as f64 / f64::from(1 << 30))
+    }
+}
+
+fn format_change(current: Duration, previous: Option<&Timings>) -> String {
+    match previous {
+        None => String::from("new"),
+        Some(previous) if previous.total().is_zero() => String::from("-"),
+        Some(previous) => {
+            let change = (current.as_secs_f64() / previous.total().as_secs_f64() - 1.0) * 100.0;
+            format!("{change:+.1}%")
+        }
+    }
+}
+
+pub fn print_table(results: &BTreeMap<u8, Timings>, previous: &BTreeMap<u8, Timings>) {
+    println!("{:<5}{:>12}{:>12}{:>12}{:>12}{:>10}", "Day", "Parse", "Part 1", "Part 2", "Total", "Change");
+    for (day, t) in results {
+        println!(
+            "{:<5}{:>12}{:>12}{:>12}{:>12}{:>10}",
+            format!("{day:02}"),
+            format_duration(t.parse),
+            format_duration(t.part1),
+            format_duration(t.part2),
+            format_duration(t.total()),
+            format_change(t.total(), previous.get(day)),
+        );
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+pub fn print_allocation_table(results: &BTreeMap<u8, [AllocStats; 3]>) {
+    println!();
+    println!("{:<5}{:<8}{:>14}{:>14}{:>14}", "Day", "Phase", "Allocations", "Allocated", "Peak");
+    for (day, phases) in results {
+        for (phase, stats) in ["parse", "part 1", "part 2"].iter().zip(phases) {
+            println!(
+                "{:<5}{:<8}{:>14}{:>14}{:>14}",
+                format!("{day:02}"), phase, stats.allocations, format_bytes(stats.allocated_bytes), format_bytes(stats.peak_bytes),
+            );
+        }
+    }
+}
@@ -0,0 +1,550 @@
+mod bench;
+mod scaffold;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use aoc_2024_rust::cancel;
+use aoc_2024_rust::days;
+use aoc_2024_rust::error::AocError;
+use aoc_2024_rust::generate;
+use aoc_2024_rust::json::Json;
+use aoc_2024_rust::ledger::{Ledger, LEDGER_FILE};
+use aoc_2024_rust::params::{parse_assignment, unknown_param, ParamConfig, CONFIG_FILE};
+use aoc_2024_rust::progress::{self, Verbosity};
+use aoc_2024_rust::solution::{read_input, Answer, DynSolution, Part, DEFAULT_VARIANT};
+use aoc_2024_rust::trace::{self, TraceEvent};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+const USAGE: &str = "usage: aoc [-q|-v] <command>
+  aoc run <day|all> [input file, or - for stdin] [--json] [--all-variants] [--budget <seconds, 0 for none>] [--trace <file>] [--param <name>=<value>]...
+  aoc replay <trace file> [--part <n>] [--variant <name>] [--step <n>]
+  aoc bench [day|all] [--runs <n>] [--budget <seconds, 0 for none>] [--param <name>=<value>]...
+  aoc params <day>
+  aoc generate <day> [--seed <n>] [--size <n>]
+  aoc new <day>
+  aoc ledger [<day> <part> <correct|too-high|too-low|wrong> <answer>]";
+
+// time each part (and variant) may take before it is cancelled, in both runs and benchmarks
+const DEFAULT_BUDGET: Duration = Duration::from_secs(300);
+
+fn input_file(day: u8) -> String {
+    format!("input/day_{day:02}.txt")
+}
+
+fn open_input(input_file: &str) -> Result<Box<dyn BufRead>, AocError> {
+    if input_file == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        let file = File::open(input_file).map_err(|e| format!("unable to open {input_file}: {e}"))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+type Registry = Vec<(u8, Box<dyn DynSolution>)>;
+type Assignments = Vec<(String, String)>;
+
+// Removes `--param name=value` options from the arguments, returning the assignments
+fn take_params(args: &[String]) -> Result<(Vec<String>, Assignments), AocError> {
+    let mut rest = Vec::new();
+    let mut params = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--param" {
+            params.push(parse_assignment(args.next().ok_or(USAGE)?)?);
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    Ok((rest, params))
+}
+
+// Applies the values from `aoc.toml`, then those from the command line, which only have to be known to one of the days
+fn configure(mut solutions: Registry, cli_params: &[(String, String)]) -> Result<Registry, AocError> {
+    let config = ParamConfig::load(CONFIG_FILE)?;
+    let mut used = vec![false; cli_params.len()];
+    for (day, solution) in &mut solutions {
+        for (name, value) in config.for_day(*day) {
+            solution.apply_param(name, value).map_err(|e| format!("{CONFIG_FILE}, day {day}: {e}"))?;
+        }
+        let declared = solution.list_params();
+        for (i, (name, value)) in cli_params.iter().enumerate() {
+            if declared.iter().any(|p| p.name == name) {
+                solution.apply_param(name, value).map_err(|e| format!("day {day}: {e}"))?;
+                used[i] = true;
+            }
+        }
+    }
+    match cli_params.iter().zip(used).find(|(_, used)| !used) {
+        Some(((name, _), _)) => Err(unknown_param(name)),
+        None => Ok(solutions),
+    }
+}
+
+fn single_day(day_arg: &str) -> Result<Registry, AocError> {
+    let day = day_arg.parse::<u8>().map_err(|_| format!("invalid day: {day_arg}"))?;
+    Ok(vec![(day, days::get(day).ok_or(format!("no solution for day {day}"))?)])
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+struct PartReport {
+    part: Part,
+    variant: &'static str,
+    message: &'static str,
+    result: Result<Answer, AocError>,
+    duration: Duration,
+    events: Vec<TraceEvent>,
+}
+
+struct DayReport {
+    day: u8,
+    parse_duration: Duration,
+    parts: Vec<PartReport>,
+    all_variants: bool,
+}
+
+#[derive(Clone, Copy)]
+struct RunOptions {
+    all_variants: bool,
+    budget: Option<Duration>,
+    trace: bool,
+}
+
+// `0` turns the budget off
+fn parse_budget(value: &str) -> Result<Option<Duration>, AocError> {
+    let budget = value.parse().ok().and_then(|s| Duration::try_from_secs_f64(s).ok()).ok_or(format!("invalid budget: {value}"))?;
+    Ok(Some(budget).filter(|b| !b.is_zero()))
+}
+
+fn solve_day(day: u8, solution: &dyn DynSolution, input_file: &str, options: RunOptions) -> Result<DayReport, AocError> {
+    let input = read_input(&mut open_input(input_file)?)?;
+    let start = Instant::now();
+    let parsed = solution.parse_input(&input)?;
+    let parse_duration = start.elapsed();
+    let mut parts = Vec::new();
+    for part in Part::both() {
+        let variants = if options.all_variants { solution.variant_names(part) } else { vec![DEFAULT_VARIANT] };
+        for variant in variants {
+            let start = Instant::now();
+            let solve = || cancel::with_budget(options.budget, || solution.solve_variant(parsed.as_ref(), part, variant));
+            let ((result, token), events) = if options.trace { trace::with_recording(solve) } else { (solve(), Vec::new()) };
+            let duration = start.elapsed();
+            // a solution that ran out of time is reported as such, even if it didn't notice the cancellation
+            let result = match options.budget {
+                Some(budget) if token.is_cancelled() => Err(bench::timed_out(budget, &token)),
+                _ => result,
+            };
+            parts.push(PartReport { part, variant, message: solution.output_message(part), result, duration, events });
+        }
+    }
+    Ok(DayReport { day, parse_duration, parts, all_variants: options.all_variants })
+}
+
+fn describe(result: &Result<Answer, AocError>) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error ({e})"),
+    }
+}
+
+// Every variant of a part is expected to give the same result as the default implementation
+fn disagreements(report: &DayReport) -> Vec<String> {
+    let defaults: Vec<&PartReport> = report.parts.iter().filter(|p| p.variant == DEFAULT_VARIANT).collect();
+    report.parts.iter().filter_map(|p| {
+        let default = defaults.iter().find(|d| d.part == p.part)?;
+        (p.result != default.result).then(|| format!(
+            "day {:02} part {}: {} gave {}, {DEFAULT_VARIANT} gave {}",
+            report.day, p.part, p.variant, describe(&p.result), describe(&default.result),
+        ))
+    }).collect()
+}
+
+fn part_number(part: Part) -> u64 {
+    match part { Part::One => 1, Part::Two => 2 }
+}
+
+// JSON Lines, one event per line, tagged with the part and variant that emitted it
+fn write_trace(report: &DayReport, path: &str) -> Result<(), AocError> {
+    let error = |e: io::Error| AocError::new(format!("unable to write {path}: {e}"));
+    let mut writer = BufWriter::new(File::create(path).map_err(error)?);
+    for p in &report.parts {
+        for e in &p.events {
+            let line = Json::object(vec![
+                ("part", Json::Number(part_number(p.part))),
+                ("variant", Json::from(p.variant)),
+                ("event", e.to_json()),
+            ]);
+            writeln!(writer, "{line}").map_err(error)?;
+        }
+    }
+    writer.flush().map_err(error)
+}
+
+fn nanos(duration: Duration) -> Json {
+    Json::Number(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+}
+
+// one JSON object per line and part, with a fixed set and order of keys
+fn print_day_json(report: &DayReport, ledger: &Ledger) {
+    for p in &report.parts {
+        let (value_type, value) = match &p.result {
+            Ok(Answer::Number(n)) => (Json::from("number"), Json::Number(*n)),
+            Ok(Answer::Text(t)) => (Json::from("text"), Json::from(t.as_str())),
+            Err(_) => (Json::Null, Json::Null),
+        };
+        let line = Json::object(vec![
+            ("day", Json::from(report.day)),
+            ("part", Json::Number(part_number(p.part))),
+            ("variant", Json::from(p.variant)),
+            ("message", Json::from(p.message)),
+            ("type", value_type),
+            ("value", value),
+            ("answer", Json::from(p.result.as_ref().ok().map(Answer::to_string))),
+            ("error", Json::from(p.result.as_ref().err().map(AocError::to_string))),
+            ("ledger", Json::from(p.result.as_ref().ok().map(|a| ledger.check(report.day, p.part, a).status()))),
+            ("timings", Json::object(vec![("parse_ns", nanos(report.parse_duration)), ("solve_ns", nanos(p.duration))])),
+        ]);
+        println!("{line}");
+    }
+}
+
+fn print_day_text(report: &DayReport, ledger: &Ledger) {
+    println!("Day {:02}", report.day);
+    for p in &report.parts {
+        // timings are only shown when comparing variants
+        let timing = if report.all_variants { format!(" ({})", bench::format_duration(p.duration)) } else { String::new() };
+        if p.variant != DEFAULT_VARIANT {
+            println!("    Variant {}: {}{timing}", p.variant, describe(&p.result));
+            continue;
+        }
+        match &p.result {
+            Ok(result) => {
+                println!("  Part {} - {}: {result}{timing}", p.part, p.message);
+                let check = ledger.check(report.day, p.part, result);
+                if check.is_problem() {
+                    println!("    WARNING: {check}");
+                }
+            }
+            Err(e) => println!("  Part {} - error calculating result: {e}{timing}", p.part),
+        }
+    }
+}
+
+// for a day whose input couldn't be read or parsed, in place of its parts
+fn print_day_error(day: u8, error: &AocError, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => {
+            println!("Day {day:02}");
+            println!("  error: {error}");
+        }
+        OutputFormat::Json => {
+            let line = Json::object(vec![
+                ("day", Json::from(day)),
+                ("part", Json::Null),
+                ("variant", Json::Null),
+                ("message", Json::Null),
+                ("type", Json::Null),
+                ("value", Json::Null),
+                ("answer", Json::Null),
+                ("error", Json::from(error.to_string())),
+                ("ledger", Json::Null),
+                ("timings", Json::Null),
+            ]);
+            println!("{line}");
+        }
+    }
+}
+
+fn print_day(report: &DayReport, ledger: &Ledger, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => print_day_text(report, ledger),
+        OutputFormat::Json => print_day_json(report, ledger),
+    }
+}
+
+fn run(args: &[String]) -> Result<(), AocError> {
+    let (args, params) = take_params(args)?;
+    let mut format = OutputFormat::Text;
+    let mut options = RunOptions { all_variants: false, budget: Some(DEFAULT_BUDGET), trace: false };
+    let mut trace_file = None;
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => format = OutputFormat::Json,
+            "--all-variants" => options.all_variants = true,
+            "--budget" => options.budget = parse_budget(args.next().ok_or(USAGE)?)?,
+            "--trace" => trace_file = Some(args.next().ok_or(USAGE)?),
+            _ => positional.push(arg),
+        }
+    }
+    options.trace = trace_file.is_some();
+    let args = positional;
+    let day_arg = args.first().ok_or(USAGE)?;
+    let ledger = Ledger::load(LEDGER_FILE)?;
+    let mut mismatches = Vec::new();
+    let mut failed_days = Vec::new();
+    if *day_arg == "all" {
+        if trace_file.is_some() { return Err(AocError::new("only a single day can be traced")); }
+        let registry = configure(days::registry(), &params)?;
+        // with the `parallel` feature, days are solved concurrently and printed in order afterwards
+        #[cfg(feature = "parallel")]
+        let reports: Vec<_> = registry.par_iter().map(|(day, solution)| solve_day(*day, solution.as_ref(), &input_file(*day), options)).collect();
+        #[cfg(not(feature = "parallel"))]
+        let reports = registry.iter().map(|(day, solution)| solve_day(*day, solution.as_ref(), &input_file(*day), options));
+        // a day that can't be solved at all (e.g. because its input is missing) doesn't stop the others
+        for ((day, _), report) in registry.iter().zip(reports) {
+            match report {
+                Ok(report) => {
+                    print_day(&report, &ledger, format);
+                    mismatches.extend(disagreements(&report));
+                }
+                Err(e) => {
+                    print_day_error(*day, &e, format);
+                    failed_days.push(format!("{day:02}"));
+                }
+            }
+        }
+    } else {
+        let (day, solution) = configure(single_day(day_arg)?, &params)?.remove(0);
+        let input_file = args.get(1).map_or(input_file(day), |f| f.to_string());
+        // the ledger only holds answers for the puzzle input, so other inputs aren't checked against it
+        let ledger = if args.len() > 1 { Ledger::default() } else { ledger };
+        let report = solve_day(day, solution.as_ref(), &input_file, options)?;
+        print_day(&report, &ledger, format);
+        if let Some(trace_file) = trace_file { write_trace(&report, trace_file)?; }
+        mismatches.extend(disagreements(&report));
+    }
+    let mut problems = Vec::new();
+    if !mismatches.is_empty() { problems.push(format!("variants disagree:\n  {}", mismatches.join("\n  "))); }
+    if !failed_days.is_empty() { problems.push(format!("unable to solve day(s) {}", failed_days.join(", "))); }
+    if problems.is_empty() { Ok(()) } else { Err(AocError::new(problems.join("\n"))) }
+}
+
+fn bench(args: &[String]) -> Result<(), AocError> {
+    let (args, params) = take_params(args)?;
+    let mut day_arg = None;
+    let mut runs = bench::DEFAULT_RUNS;
+    let mut budget = Some(DEFAULT_BUDGET);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--runs" {
+            let n = args.next().ok_or(USAGE)?;
+            runs = n.parse().map_err(|_| format!("invalid number of runs: {n}"))?;
+        } else if arg == "--budget" {
+            budget = parse_budget(args.next().ok_or(USAGE)?)?;
+        } else if day_arg.is_none() {
+            day_arg = Some(arg.as_str());
+        } else {
+            return Err(AocError::new(USAGE));
+        }
+    }
+    let solutions = match day_arg {
+        None | Some("all") => days::registry(),
+        Some(day_arg) => single_day(day_arg)?,
+    };
+    let solutions = configure(solutions, &params)?;
+
+    let mut history = bench::load_history(bench::BENCH_FILE)?;
+    let mut results = BTreeMap::new();
+    #[cfg(feature = "alloc-stats")]
+    let mut allocations = BTreeMap::new();
+    for (day, solution) in solutions {
+        let input_file = input_file(day);
+        let input = match open_input(&input_file) {
+            Ok(mut reader) => read_input(&mut reader)?,
+            Err(e) if day_arg.is_none_or(|d| d == "all") => {
+                eprintln!("Skipping day {day:02}: {e}");
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let timings = match bench::bench_day(solution.as_ref(), &input, runs, budget) {
+            Ok(timings) => timings,
+            Err(e) if day_arg.is_none_or(|d| d == "all") => {
+                eprintln!("Skipping day {day:02}: {e}");
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        results.insert(day, timings);
+        #[cfg(feature = "alloc-stats")]
+        allocations.insert(day, bench::measure_allocations(solution.as_ref(), &input, budget)?);
+    }
+    bench::print_table(&results, &history);
+    #[cfg(feature = "alloc-stats")]
+    bench::print_allocation_table(&allocations);
+    history.extend(results);
+    bench::save_history(bench::BENCH_FILE, &history)
+}
+
+fn replay(args: &[String]) -> Result<(), AocError> {
+    let mut trace_file = None;
+    let mut part = 1;
+    let mut variant = DEFAULT_VARIANT;
+    let mut step = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let n = args.next().ok_or(USAGE)?;
+                part = n.parse().map_err(|_| format!("invalid part: {n}"))?;
+            }
+            "--variant" => variant = args.next().ok_or(USAGE)?,
+            "--step" => {
+                let n = args.next().ok_or(USAGE)?;
+                step = Some(n.parse().map_err(|_| format!("invalid step: {n}"))?);
+            }
+            _ if trace_file.is_none() => trace_file = Some(arg),
+            _ => return Err(AocError::new(USAGE)),
+        }
+    }
+    let trace_file = trace_file.ok_or(USAGE)?;
+    let contents = std::fs::read_to_string(trace_file).map_err(|e| format!("unable to read {trace_file}: {e}"))?;
+    let mut events = Vec::new();
+    for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+        let json: Json = line.parse()?;
+        if json.get("part").and_then(Json::as_u64) != Some(part) || json.get("variant").and_then(Json::as_str) != Some(variant) {
+            continue;
+        }
+        let event = json.get("event").and_then(TraceEvent::from_json);
+        events.push(event.ok_or_else(|| AocError::at(&contents, line, "invalid trace event"))?);
+    }
+    let last_step = events.iter().map(|e| e.step).max().ok_or(format!("no events for part {part} ({variant}) in {trace_file}"))?;
+    let step = step.unwrap_or(last_step).min(last_step);
+    println!("Step {step} of {last_step}");
+    for row in trace::replay(&events, step)? {
+        println!("{row}");
+    }
+    for e in events.iter().filter(|e| e.step == step) {
+        println!("{:?}", e.event);
+    }
+    Ok(())
+}
+
+fn params(args: &[String]) -> Result<(), AocError> {
+    let [day_arg] = args else { return Err(AocError::new(USAGE)); };
+    let (day, solution) = configure(single_day(day_arg)?, &[])?.remove(0);
+    let defaults = days::get(day).map(|s| s.list_params()).unwrap_or_default();
+    let params = solution.list_params();
+    if params.is_empty() {
+        println!("Day {day:02} has no parameters");
+    }
+    for (param, default) in params.iter().zip(defaults) {
+        println!("{} = {} (default: {}) - {}", param.name, param.value, default.value, param.description);
+    }
+    Ok(())
+}
+
+fn generate(args: &[String]) -> Result<(), AocError> {
+    let mut day_arg = None;
+    let mut seed = 0;
+    let mut size = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let n = args.next().ok_or(USAGE)?;
+                seed = n.parse().map_err(|_| format!("invalid seed: {n}"))?;
+            }
+            "--size" => {
+                let n = args.next().ok_or(USAGE)?;
+                size = Some(n.parse().map_err(|_| format!("invalid size: {n}"))?);
+            }
+            _ if day_arg.is_none() => day_arg = Some(arg),
+            _ => return Err(AocError::new(USAGE)),
+        }
+    }
+    let day_arg = day_arg.ok_or(USAGE)?;
+    let day = day_arg.parse::<u8>().map_err(|_| format!("invalid day: {day_arg}"))?;
+    print!("{}", generate::generate(day, seed, size).ok_or(format!("no input generator for day {day}"))?);
+    Ok(())
+}
+
+fn new(args: &[String]) -> Result<(), AocError> {
+    let [day_arg] = args else { return Err(AocError::new(USAGE)); };
+    let day = day_arg.parse::<u8>().map_err(|_| format!("invalid day: {day_arg}"))?;
+    scaffold::new_day(day, &input_file(day))
+}
+
+fn ledger(args: &[String]) -> Result<(), AocError> {
+    let mut ledger = Ledger::load(LEDGER_FILE)?;
+    match args {
+        [] => {
+            for e in ledger.entries() {
+                println!("Day {:02} part {} - {}: {}", e.day, e.part, e.verdict, e.answer);
+            }
+            Ok(())
+        }
+        [day, part, verdict, answer] => {
+            let day = day.parse::<u8>().map_err(|_| format!("invalid day: {day}"))?;
+            ledger.record(day, part.parse()?, verdict.parse()?, answer.parse()?);
+            ledger.save(LEDGER_FILE)
+        }
+        _ => Err(AocError::new(USAGE)),
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    // benchmarks are run quietly unless asked otherwise, so they don't measure terminal output
+    let default_verbosity = if args.first().is_some_and(|a| a == "bench") { Verbosity::Quiet } else { Verbosity::Normal };
+    let mut verbosity = None;
+    args.retain(|arg| match arg.as_str() {
+        "-q" | "--quiet" => { verbosity = Some(Verbosity::Quiet); false }
+        "-v" | "--verbose" => { verbosity = Some(Verbosity::Verbose); false }
+        _ => true,
+    });
+    progress::set_verbosity(verbosity.unwrap_or(default_verbosity));
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("replay") => replay(&args[1..]),
+        Some("params") => params(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("ledger") => ledger(&args[1..]),
+        _ => Err(AocError::new(USAGE)),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e.render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part_report(part: Part, variant: &'static str, result: Result<Answer, AocError>) -> PartReport {
+        PartReport { part, variant, message: "Total", result, duration: Duration::ZERO, events: Vec::new() }
+    }
+
+    #[test]
+    fn variants_disagree() {
+        let report = DayReport {
+            day: 17,
+            parse_duration: Duration::ZERO,
+            parts: vec![
+                part_report(Part::One, DEFAULT_VARIANT, Ok(Answer::Number(1))),
+                part_report(Part::One, "fast", Ok(Answer::Number(1))),
+                part_report(Part::Two, DEFAULT_VARIANT, Ok(Answer::Number(2))),
+                part_report(Part::Two, "slow", Err(AocError::new("gave up"))),
+            ],
+            all_variants: true,
+        };
+        assert_eq!(disagreements(&report), ["day 17 part 2: slow gave error (gave up), default gave 2"]);
+    }
+}
@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::Path;
+use aoc_2024_rust::error::AocError;
+
+const TEMPLATE: &str = include_str!("../../days/template.rs");
+const DAYS_DIR: &str = "src/days";
+
+fn write_new_file(path: &str, contents: &str) -> Result<(), AocError> {
+    if Path::new(path).exists() {
+        return Err(AocError::new(format!("{path} already exists")));
+    }
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("unable to create {}: {e}", dir.display()))?;
+    }
+    fs::write(path, contents).map_err(|e| AocError::new(format!("unable to write {path}: {e}")))?;
+    println!("Created {path}");
+    Ok(())
+}
+
+pub fn solution_source(day: u8) -> String {
+    // the template starts with a comment describing its own use, which is dropped
+    let body = TEMPLATE.split_once("\n\n").map_or(TEMPLATE, |(_, body)| body);
+    body.replace("XX", &format!("{day:02}"))
+}
+
+// Inserts `line` into the block of lines starting with `prefix`, keeping the block sorted by day number
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, line: String, day_of: impl Fn(&str) -> Option<u8>, day: u8) -> Result<(), AocError> {
+    let block: Vec<usize> = lines.iter().enumerate().filter(|(_, l)| l.starts_with(prefix)).map(|(i, _)| i).collect();
+    let last = *block.last().ok_or_else(|| AocError::new(format!("no lines starting with {prefix:?} in {DAYS_DIR}/mod.rs")))?;
+    if block.iter().any(|&i| day_of(&lines[i]) == Some(day)) {
+        return Err(AocError::new(format!("day {day} is already registered")));
+    }
+    let position = block.iter().copied().find(|&i| day_of(&lines[i]).is_some_and(|d| d > day)).unwrap_or(last + 1);
+    lines.insert(position, line);
+    Ok(())
+}
+
+pub fn register_day(mod_source: &str, day: u8) -> Result<String, AocError> {
+    let mut lines: Vec<String> = mod_source.lines().map(String::from).collect();
+    insert_sorted(
+        &mut lines, "pub mod day_", format!("pub mod day_{day:02};"),
+        |l| l.strip_prefix("pub mod day_")?.strip_suffix(';')?.parse().ok(), day,
+    )?;
+    insert_sorted(
+        &mut lines, "        (", format!("        ({day}, Box::new(day_{day:02}::Day{day:02})),"),
+        |l| l.trim_start().strip_prefix('(')?.split_once(',')?.0.parse().ok(), day,
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+pub fn new_day(day: u8, input_file: &str) -> Result<(), AocError> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::new(format!("invalid day: {day}")));
+    }
+    let mod_file = format!("{DAYS_DIR}/mod.rs");
+    let mod_source = fs::read_to_string(&mod_file).map_err(|e| format!("unable to read {mod_file}: {e}"))?;
+    let mod_source = register_day(&mod_source, day)?;
+
+    write_new_file(&format!("{DAYS_DIR}/day_{day:02}.rs"), &solution_source(day))?;
+    write_new_file(&format!("{DAYS_DIR}/examples/day_{day:02}.txt"), "")?;
+    if !Path::new(input_file).exists() {
+        write_new_file(input_file, "")?;
+    }
+    fs::write(&mod_file, mod_source).map_err(|e| format!("unable to write {mod_file}: {e}"))?;
+    println!("Registered day {day} in {mod_file}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_SOURCE: &str = "pub mod day_01;\npub mod day_09;\n\npub fn registry() {\n    vec![\n        (1, Box::new(day_01::Day01)),\n        (9, Box::new(day_09::Day09)),\n    ]\n}\n";
+
+    #[test]
+    fn register_between_days() {
+        assert_eq!(
+            register_day(MOD_SOURCE, 8),
+            Ok(String::from("pub mod day_01;\npub mod day_08;\npub mod day_09;\n\npub fn registry() {\n    vec![\n        (1, Box::new(day_01::Day01)),\n        (8, Box::new(day_08::Day08)),\n        (9, Box::new(day_09::Day09)),\n    ]\n}\n")),
+        );
+        assert!(register_day(MOD_SOURCE, 9).is_err());
+    }
+
+    #[test]
+    fn register_in_days_module() {
+        let source = register_day(include_str!("../../days/mod.rs"), 19).unwrap();
+        assert!(source.contains("pub mod day_18;\npub mod day_19;\n"));
+        assert!(source.contains("        (19, Box::new(day_19::Day19)),\n    ]"));
+        assert!(source.contains("#[cfg(test)]\nmod template;\n"));
+    }
+
+    #[test]
+    fn generate_solution() {
+        let source = solution_source(19);
+        assert!(source.starts_with("use "));
+        assert!(source.contains("pub struct Day19;"));
+        assert!(source.contains("include_str!(\"examples/day_19.txt\")"));
+        assert!(!source.contains("XX"));
+    }
+}
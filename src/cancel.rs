@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use crate::error::AocError;
+
+// Cooperative cancellation: long-running solutions poll the token in their hot loops and stop once it is cancelled.
+// Clones share the same state, so a token can be handed to other threads.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<TokenState>,
+}
+
+#[derive(Debug, Default)]
+struct TokenState {
+    cancelled: AtomicBool,
+    progress: Mutex<Option<String>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    // cheap enough to be called on every iteration
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn check(&self) -> Result<(), AocError> {
+        if self.is_cancelled() { Err(AocError::new("cancelled")) } else { Ok(()) }
+    }
+
+    // Records how far the solution got, to be reported if it is cancelled
+    pub fn set_progress(&self, progress: impl Display) {
+        if let Ok(mut p) = self.inner.progress.lock() {
+            *p = Some(progress.to_string());
+        }
+    }
+
+    pub fn last_progress(&self) -> Option<String> {
+        self.inner.progress.lock().ok()?.clone()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+// The token of the solution running on this thread, or one that is never cancelled.
+// Solutions that iterate in parallel should get it before handing work to other threads.
+pub fn current() -> CancellationToken {
+    CURRENT.with_borrow(|token| token.clone()).unwrap_or_default()
+}
+
+// Puts the previous token back when dropped, so it is restored even if the closure panics
+struct RestoreToken(Option<CancellationToken>);
+
+impl Drop for RestoreToken {
+    fn drop(&mut self) {
+        CURRENT.set(self.0.take());
+    }
+}
+
+pub fn with_token<T>(token: &CancellationToken, f: impl FnOnce() -> T) -> T {
+    let _restore = RestoreToken(CURRENT.replace(Some(token.clone())));
+    f()
+}
+
+// Runs `f` with a new token, which is cancelled once `budget` has passed
+pub fn with_budget<T>(budget: Option<Duration>, f: impl FnOnce() -> T) -> (T, CancellationToken) {
+    let token = CancellationToken::new();
+    let result = thread::scope(|s| {
+        // the watchdog stops waiting as soon as the sender is dropped
+        let (sender, receiver) = mpsc::channel::<()>();
+        if let Some(budget) = budget {
+            let token = token.clone();
+            s.spawn(move || {
+                if receiver.recv_timeout(budget) == Err(RecvTimeoutError::Timeout) { token.cancel(); }
+            });
+        }
+        let result = with_token(&token, f);
+        drop(sender);
+        result
+    });
+    (result, token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_cancels() {
+        let (result, token) = with_budget(Some(Duration::from_millis(10)), || {
+            let token = current();
+            let mut i = 0u64;
+            while !token.is_cancelled() {
+                i += 1;
+                token.set_progress(i);
+            }
+            token.check()
+        });
+        assert!(result.is_err());
+        assert!(token.is_cancelled());
+        assert!(token.last_progress().is_some());
+        assert!(!current().is_cancelled());
+    }
+
+    #[test]
+    fn restored_after_panic() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let result = std::panic::catch_unwind(|| with_token(&token, || panic!("solution failed")));
+        assert!(result.is_err());
+        assert!(!current().is_cancelled());
+    }
+
+    #[test]
+    fn within_budget() {
+        let (result, token) = with_budget(Some(Duration::from_secs(60)), || current().check());
+        assert_eq!(result, Ok(()));
+        assert!(!token.is_cancelled());
+        assert_eq!(token.last_progress(), None);
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::iter::zip;
+use crate::error::AocError;
+use crate::parsing::space_list;
+use crate::solution::{Answer, Solution};
+
+pub struct Day01;
+
+fn parse_sort_lists(input: &str) -> Result<(Vec<u32>, Vec<u32>), AocError> {
+    let mut list_1: Vec<u32> = Vec::new();
+    let mut list_2: Vec<u32> = Vec::new();
+    for l in input.lines() {
+        let nums = space_list::<u32>(input, l)?;
+        if nums.len() != 2 { return Err(AocError::at(input, l, "incorrect number of numbers in line")); }
+        list_1.push(nums[0]);
+        list_2.push(nums[1]);
+    }
+    list_1.sort();
+    list_2.sort();
+    Ok((list_1, list_2))
+}
+
+fn calculate_similarity_score(list_1: &[u32], list_2: &[u32]) -> u32 {
+    let mut occurrences: HashMap<u32, u32> = HashMap::new();
+    list_2.iter().for_each(|&num| {
+        let mut v_new = 1;
+        if let Some(v_old) = occurrences.get(&num) { v_new += v_old; }
+        occurrences.insert(num, v_new);
+    });
+    list_1
+        .iter()
+        .map(|&num| if let Some(occ) = occurrences.get(&num) { num * occ } else { 0 })
+        .sum()
+}
+
+impl Solution for Day01 {
+    type Input = (Vec<u32>, Vec<u32>);
+
+    const PART_1_MESSAGE: &'static str = "Total distance";
+    const PART_2_MESSAGE: &'static str = "Similarity score";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_sort_lists(input)
+    }
+
+    fn part1(&self, (list_1, list_2): &Self::Input) -> Result<Answer, AocError> {
+        Ok(zip(list_1, list_2).map(|(x, y)| x.abs_diff(*y)).sum::<u32>().into())
+    }
+
+    fn part2(&self, (list_1, list_2): &Self::Input) -> Result<Answer, AocError> {
+        Ok(calculate_similarity_score(list_1, list_2).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_01.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day01.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(11)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day01.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(31)));
+    }
+}
@@ -0,0 +1,75 @@
+use std::iter::zip;
+use crate::error::AocError;
+use crate::parsing::space_list;
+use crate::solution::{Answer, Solution};
+
+pub struct Day02;
+
+fn check_report_safety(report: &[u32]) -> bool {
+    let increasing = report[1] > report[0];
+    let mut diffs: Vec<u32> = Vec::new();
+    for i in 1..report.len() {
+        if (increasing & (report[i] < report[i - 1]))
+            | (!increasing & (report[i] > report[i - 1]))
+        { return false; }
+        diffs.push(report[i].abs_diff(report[i - 1]));
+    }
+    diffs.iter().map(|d| (*d >= 1) & (*d <= 3)).reduce(|acc, e| acc & e).unwrap()
+}
+
+fn clone_without(vec: &[u32], i: usize) -> Vec<u32> {
+    let mut vec = vec.to_vec();
+    vec.remove(i);
+    vec
+}
+
+fn check_report_safety_with_dampener(report: &[u32]) -> bool {
+    for i in 0..report.len() {
+        if check_report_safety(&clone_without(report, i)) { return true; }
+    }
+    false
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<u32>>;
+
+    const PART_1_MESSAGE: &'static str = "Number of safe reports";
+    const PART_2_MESSAGE: &'static str = "Number of safe reports";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| space_list(input, line))
+            .collect()
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Result<Answer, AocError> {
+        Ok(reports.iter().map(|r| check_report_safety(r)).map(|s| if s { 1u32 } else { 0u32 }).sum::<u32>().into())
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Result<Answer, AocError> {
+        let fully_safe_reports: Vec<bool> = reports.iter().map(|r| check_report_safety(r)).collect();
+        let dampened_safe_reports: Vec<bool> = reports.iter().map(|r| check_report_safety_with_dampener(r)).collect();
+        let overall_safe_reports: Vec<bool> = zip(fully_safe_reports, dampened_safe_reports).map(|(s1, s2)| s1 | s2).collect();
+        Ok(overall_safe_reports.iter().map(|s| if *s { 1u32 } else { 0u32 }).sum::<u32>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_02.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day02.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(2)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day02.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(4)));
+    }
+}
@@ -0,0 +1,79 @@
+use regex::Regex;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day03;
+
+fn remove_disabled_sections(memory: String) -> String {
+    let mut include_current = true;
+    memory.char_indices().filter_map(|(i, char)| {
+        if memory[i..].starts_with("do()") { include_current = true; }
+        else if memory[i..].starts_with("don't()") { include_current = false; }
+        if include_current { Some(char) } else { None }
+    }).collect()
+}
+
+fn sum_multiplications(memory: &str) -> Result<u64, AocError> {
+    let instruction_regex = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+    let mut total: u64 = 0;
+    for c in instruction_regex.captures_iter(memory) {
+        let (instruction, [x, y]) = c.extract();
+        let too_large = || AocError::new(format!("result of {instruction} is too large"));
+        let product = str::parse::<u64>(x).ok().zip(str::parse::<u64>(y).ok()).and_then(|(x, y)| x.checked_mul(y)).ok_or_else(too_large)?;
+        total = total.checked_add(product).ok_or_else(too_large)?;
+    }
+    Ok(total)
+}
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    const PART_1_MESSAGE: &'static str = "Calculated total";
+    const PART_2_MESSAGE: &'static str = "Calculated total";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<Answer, AocError> {
+        let mut text = String::from("");
+        for line in lines {
+            text.push(' ');  // avoid line break removal causing unwanted matches
+            text.push_str(line);
+        }
+        Ok(sum_multiplications(&text)?.into())
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<Answer, AocError> {
+        let full_text: String = lines.concat();
+        let enabled_memory = remove_disabled_sections(full_text);
+        Ok(sum_multiplications(&enabled_memory)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_03.txt");
+    const EXAMPLE_PART_2: &str = include_str!("examples/day_03_part_2.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day03.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(161)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day03.parse_and_solve(EXAMPLE_PART_2, Part::Two), Ok(Answer::Number(48)));
+    }
+
+    #[test]
+    fn short_and_invalid_memory() {
+        assert_eq!(Day03.parse_and_solve("mul", Part::Two), Ok(Answer::Number(0)));
+        assert_eq!(Day03.parse_and_solve("dö()mul(2,3)", Part::Two), Ok(Answer::Number(6)));
+        assert!(Day03.parse_and_solve("mul(99999999999999999999,2)", Part::One).is_err());
+        assert!(Day03.parse_and_solve("mul(4294967296,4294967296)", Part::One).is_err());
+    }
+}
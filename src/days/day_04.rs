@@ -1,16 +1,15 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 
-const OUTPUT_MESSAGE: &str = "Number of occurrences";
-const INPUT_FILE: &str = "input/day_04.txt";
+pub struct Day04;
 
 const GRID_WIDTH: usize = 141;
 const GRID_HEIGHT: usize = 140;
 
 #[derive(Debug)]
-struct Grid {
+pub struct Grid {
     _grid: [[char; GRID_WIDTH]; GRID_HEIGHT],
 }
 
@@ -27,7 +26,7 @@ impl Grid {
         self.get_raw(coords.0, coords.1)
     }
 
-    fn set_raw(&mut self, x: usize, y: usize, c: char) -> () {
+    fn set_raw(&mut self, x: usize, y: usize, c: char) {
         self._grid[x][y] = c;
     }
 
@@ -66,9 +65,9 @@ impl Coords {
     }
 }
 
-fn find_x(grid: &Grid) -> Vec<Coords> {
+fn find_char(grid: &Grid, needle: char) -> Vec<Coords> {
     grid.iter().enumerate().flat_map(|(i, row)| {
-        row.iter().enumerate().filter_map(move |(j, c)| if *c == 'X' { Some(Coords(i, j)) } else { None } )
+        row.iter().enumerate().filter_map(move |(j, c)| if *c == needle { Some(Coords(i, j)) } else { None } )
     }).collect()
 }
 
@@ -91,24 +90,77 @@ fn count_words_from_x_single(x_position: &Coords, grid: &Grid) -> usize {
     Direction::iter().map(|d| if check_word(x_position, grid, d) { 1 } else { 0 } ).sum()
 }
 
-fn count_words_from_x(x_positions: &Vec<Coords>, grid: &Grid) -> usize {
+fn count_words_from_x(x_positions: &[Coords], grid: &Grid) -> usize {
     x_positions.iter().map(|x_pos| count_words_from_x_single(x_pos, grid)).sum()
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<usize, ()> {
-    let mut grid = Grid::create();
-    lines.enumerate().for_each(|(i, l)| {
-        let line = l.unwrap();
-        line.chars().enumerate().for_each(|(j, c)| grid.set_raw(i, j, c));
-    });
-    let x_positions = find_x(&grid);
-    let num_words = count_words_from_x(&x_positions, &grid);
-    Ok(num_words)
+fn is_valid_x_mas(a_position: &Coords, grid: &Grid) -> bool {
+    if let (Ok(ne), Ok(nw), Ok(se), Ok(sw)) = (
+        a_position.moved(Direction::Northeast),
+        a_position.moved(Direction::Northwest),
+        a_position.moved(Direction::Southeast),
+        a_position.moved(Direction::Southwest),
+    ) {
+        let c_ne = grid.get(ne);
+        let c_nw = grid.get(nw);
+        let c_se = grid.get(se);
+        let c_sw = grid.get(sw);
+
+        if
+            ((c_ne, c_sw) == ('M', 'S') || (c_ne, c_sw) == ('S', 'M'))
+            && ((c_nw, c_se) == ('M', 'S') || (c_nw, c_se) == ('S', 'M'))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+fn count_valid_x_mas(a_positions: &[Coords], grid: &Grid) -> usize {
+    a_positions.iter().map(|a_pos| if is_valid_x_mas(a_pos, grid) { 1 } else { 0 }).sum()
+}
+
+impl Solution for Day04 {
+    type Input = Grid;
+
+    const PART_1_MESSAGE: &'static str = "Number of occurrences";
+    const PART_2_MESSAGE: &'static str = "Number of occurrences";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let mut grid = Grid::create();
+        for (i, line) in input.lines().enumerate() {
+            if i >= GRID_HEIGHT { return Err(AocError::at(input, line, format!("grid has more than {GRID_HEIGHT} rows"))); }
+            if line.chars().count() > GRID_WIDTH { return Err(AocError::at(input, line, format!("row is longer than {GRID_WIDTH} characters"))); }
+            line.chars().enumerate().for_each(|(j, c)| grid.set_raw(i, j, c));
+        }
+        Ok(grid)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let x_positions = find_char(grid, 'X');
+        count_words_from_x(&x_positions, grid).try_into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let a_positions = find_char(grid, 'A');
+        count_valid_x_mas(&a_positions, grid).try_into()
+    }
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_04.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day04.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(18)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day04.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(9)));
+    }
 }
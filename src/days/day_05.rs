@@ -0,0 +1,126 @@
+use crate::error::AocError;
+use crate::graph::{parse_edges, Graph};
+use crate::parsing::{comma_list, exact_sections};
+use crate::solution::{Answer, Part, Solution, Variant};
+
+pub struct Day05;
+
+#[derive(Copy, Clone)]
+pub struct OrderingRule (usize, usize);
+
+impl OrderingRule {
+    fn is_violated(&self, update: &[usize]) -> bool {
+        self.is_violated_by(update).is_some()
+    }
+
+    fn is_violated_by(&self, update: &[usize]) -> Option<(usize, usize)> {
+        if let Some(pos_0) = update.iter().position(|&n| n == self.0) {
+            if let Some(pos_1) = update.iter().position(|&n| n == self.1) {
+                if pos_0 > pos_1 { return Some((pos_0, pos_1)); }
+            }
+        }
+        None
+    }
+}
+
+fn parse_ordering_rules(input: &str, section: &str) -> Result<Vec<OrderingRule>, AocError> {
+    Ok(parse_edges(input, section, "|")?.into_iter().map(|(first, second)| OrderingRule (first, second)).collect())
+}
+
+fn parse_updates(input: &str, section: &str) -> Result<Vec<Vec<usize>>, AocError> {
+    section.lines().map(|line| {
+        comma_list(input, line)
+    }).collect()
+}
+
+fn middle_element<T: Clone>(vec: &[T]) -> T {
+    vec[vec.len() / 2].clone()
+}
+
+// The rules as a whole may contain cycles, but those that apply to a single update don't
+fn sort_update(update: &[usize], rules_graph: &Graph<usize>) -> Result<Vec<usize>, AocError> {
+    let mut graph = rules_graph.induced_subgraph(update);
+    // pages without any rules still have to be placed
+    update.iter().for_each(|&page| { graph.add_node(page); });
+    graph.topological_sort().map_err(|cycle| AocError::new(format!("ordering rules form a cycle: {cycle:?}")))
+}
+
+fn fix_update(update: &[usize], ordering_rules: &[OrderingRule]) -> Vec<usize> {
+    let mut fixed_update = update.to_vec();
+    loop {
+        let mut all_fulfilled = true;
+        for rule in ordering_rules {
+            if let Some((pos_0, pos_1)) = rule.is_violated_by(&fixed_update) {
+                fixed_update.swap(pos_0, pos_1);
+                all_fulfilled = false;
+                break
+            }
+        }
+        if all_fulfilled { return fixed_update; }
+    }
+}
+
+impl Solution for Day05 {
+    type Input = (Vec<OrderingRule>, Vec<Vec<usize>>);
+
+    const PART_1_MESSAGE: &'static str = "Sum of middle page numbers";
+    const PART_2_MESSAGE: &'static str = "Sum of middle page numbers";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let [ordering_rules, updates] = exact_sections(input)?;
+        Ok((
+            parse_ordering_rules(input, ordering_rules)?,
+            parse_updates(input, updates)?,
+        ))
+    }
+
+    fn part1(&self, (ordering_rules, updates): &Self::Input) -> Result<Answer, AocError> {
+        let fulfilled_middle_nums = updates.iter().filter_map(|update| {
+            if ordering_rules.iter().any(|r| r.is_violated(update)) { None }
+            else { Some(middle_element(update)) }
+        }).collect::<Vec<usize>>();
+        fulfilled_middle_nums.iter().sum::<usize>().try_into()
+    }
+
+    fn part2(&self, (ordering_rules, updates): &Self::Input) -> Result<Answer, AocError> {
+        let rules_graph = Graph::directed(ordering_rules.iter().map(|r| (r.0, r.1)));
+        let incorrect_updates = updates.iter().filter(|&update| ordering_rules.iter().any(|r| r.is_violated(update)));
+        let fixed_middle_nums: Vec<usize> = incorrect_updates.map(|update| Ok(middle_element(&sort_update(update, &rules_graph)?))).collect::<Result<_, AocError>>()?;
+        fixed_middle_nums.iter().sum::<usize>().try_into()
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![Variant {
+            part: Part::Two,
+            name: "pairwise_swaps",
+            solve: |_, (ordering_rules, updates)| {
+                let incorrect_updates = updates.iter().filter(|&update| ordering_rules.iter().any(|r| r.is_violated(update)));
+                incorrect_updates.map(|update| middle_element(&fix_update(update, ordering_rules))).sum::<usize>().try_into()
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::DynSolution;
+
+    const EXAMPLE: &str = include_str!("examples/day_05.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day05.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(143)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day05.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(123)));
+    }
+
+    #[test]
+    fn part_2_pairwise_swaps() {
+        let input = Day05.parse_input(EXAMPLE).unwrap();
+        assert_eq!(Day05.solve_variant(input.as_ref(), Part::Two, "pairwise_swaps"), Ok(Answer::Number(123)));
+    }
+}
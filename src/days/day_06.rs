@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use crate::cancel;
+use crate::coord_grid::{Direction, str_to_char_grid, Grid};
+use crate::progress::ProgressBar;
+use crate::error::AocError;
+use crate::simulation::{self, Simulation};
+use crate::solution::{Answer, Solution};
+use crate::trace::{self, Event};
+
+pub struct Day06;
+
+type MapGrid = Grid<char>;
+
+fn follow_path(starting_coords: (usize, usize), starting_direction: Direction, grid: &MapGrid) -> HashSet<(usize, usize)> {
+    let mut coords = starting_coords;
+    let mut direction = starting_direction;
+    let mut visited_positions = HashSet::from([starting_coords]);
+    trace::record(|| Event::Grid { rows: trace::grid_rows(grid.iter_rows(), |c| c) });
+    while let Ok(new_coords) = grid.move_coords(coords, direction) {
+        trace::step();
+        trace::record(|| Event::Move { entity: String::from("guard"), from: coords, to: new_coords });
+        coords = new_coords;
+        visited_positions.insert(coords);
+        while grid.move_coords(coords, direction).is_ok() && grid.get(grid.move_coords(coords, direction).unwrap()).unwrap() == '#' {
+            direction = direction.turn_90_clockwise();
+            trace::record(|| Event::Turn { entity: String::from("guard"), at: coords, direction });
+        }
+    }
+    visited_positions
+}
+
+// The guard moves one tile at a time, or turns if the tile ahead is an obstacle
+#[derive(Clone)]
+struct Guard<'a> {
+    grid: &'a MapGrid,
+    coords: (usize, usize),
+    direction: Direction,
+    left_area: bool,
+}
+
+impl Simulation for Guard<'_> {
+    type Key = ((usize, usize), Direction);
+
+    fn step(&mut self) {
+        match self.grid.move_coords(self.coords, self.direction) {
+            Ok(ahead) if self.grid.get(ahead).unwrap() == '#' => self.direction = self.direction.turn_90_clockwise(),
+            Ok(ahead) => self.coords = ahead,
+            Err(_) => self.left_area = true,
+        }
+    }
+
+    fn state_key(&self) -> Self::Key {
+        (self.coords, self.direction)
+    }
+
+    fn is_done(&self) -> bool {
+        self.left_area
+    }
+}
+
+fn is_infinite_loop(starting_coords: (usize, usize), starting_direction: Direction, grid: &MapGrid) -> Result<bool, AocError> {
+    let guard = Guard { grid, coords: starting_coords, direction: starting_direction, left_area: false };
+    Ok(simulation::find_cycle(&guard)?.is_some())
+}
+
+fn find_obstacle_positions(starting_coords: (usize, usize), starting_direction: Direction, grid: &MapGrid) -> Result<Vec<(usize, usize)>, AocError> {
+    let progress = ProgressBar::new("Testing positions", grid.width() * grid.height());
+    // with the `parallel` feature, the positions are tested on other threads, which have to be handed the token
+    let token = cancel::current();
+    let is_valid_position = |(i, j): (usize, usize)| {
+        progress.inc();
+        if (i, j) == starting_coords || grid.get((i, j)).unwrap() == '#' { return Ok(None); }
+        let mut new_grid = grid.clone();
+        let _ = new_grid.set((i, j), '#');
+        let is_loop = cancel::with_token(&token, || is_infinite_loop(starting_coords, starting_direction, &new_grid))?;
+        Ok(is_loop.then_some((i, j)))
+    };
+    #[cfg(feature = "parallel")]
+    let valid_positions = grid.par_iter_coords().filter_map(|c| is_valid_position(c).transpose()).collect();
+    #[cfg(not(feature = "parallel"))]
+    let valid_positions = grid.iter_coords().filter_map(|c| is_valid_position(c).transpose()).collect();
+    valid_positions
+}
+
+impl Solution for Day06 {
+    type Input = MapGrid;
+
+    const PART_1_MESSAGE: &'static str = "Visited positions";
+    const PART_2_MESSAGE: &'static str = "Possible obstacle positions";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        str_to_char_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let starting_coords = grid.position('^').ok_or("unable to find starting position")?;
+        let visited_positions = follow_path(starting_coords, Direction::North, grid);
+        visited_positions.len().try_into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let starting_coords = grid.position('^').ok_or("unable to find starting position")?;
+        let valid_positions = find_obstacle_positions(starting_coords, Direction::North, grid)?;
+        valid_positions.len().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_06.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day06.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(41)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day06.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(6)));
+    }
+}
@@ -0,0 +1,117 @@
+use std::iter::repeat_n;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use crate::error::AocError;
+use crate::math;
+use crate::parsing::key_values;
+use crate::solution::{Answer, Solution};
+
+pub struct Day07;
+
+#[derive(Copy, Clone, Debug)]
+enum Operator {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+type Equation = (u64, Vec<u64>);
+
+fn can_be_calculated(data_item: &Equation, alternative_operators: &[Operator]) -> bool {
+    let (test_value, numbers) = data_item;
+    let operators = repeat_n(Operator::Add, numbers.len() - 1).collect();
+    recurse(test_value, numbers, &operators, 0, alternative_operators)
+}
+
+fn recurse(test_value: &u64, numbers: &[u64], operators: &Vec<Operator>, test_pos: usize, alternative_operators: &[Operator]) -> bool {
+    match calculate(numbers, operators) {
+        Ok(total) if total == *test_value => true,
+        // the operators before test_pos are the same for the whole branch, so every combination in it overflows
+        Err(overflow_pos) if overflow_pos < test_pos => false,
+        _ if test_pos == operators.len() => false,
+        _ => {
+            recurse(test_value, numbers, operators, test_pos + 1, alternative_operators)
+                || alternative_operators.iter().any(|&operator| {
+                    let mut new_operators = operators.clone();
+                    new_operators[test_pos] = operator;
+                    recurse(test_value, numbers, &new_operators, test_pos + 1, alternative_operators)
+                })
+        }
+    }
+}
+
+// The result of applying the operators from left to right, or the position of the first operator whose result
+// doesn't fit in a u64 (such a combination can't match any test value)
+fn calculate(numbers: &[u64], operators: &[Operator]) -> Result<u64, usize> {
+    let mut total = numbers[0];
+    for (i, (operator, &n)) in operators.iter().zip(&numbers[1..]).enumerate() {
+        let result = match operator {
+            Operator::Add => total.checked_add(n),
+            Operator::Multiply => total.checked_mul(n),
+            Operator::Concatenate => math::concat(total, n),
+        };
+        total = result.ok_or(i)?;
+    }
+    Ok(total)
+}
+
+fn total_calibration_result(data: &[Equation], alternative_operators: &[Operator]) -> u64 {
+    #[cfg(feature = "parallel")]
+    let data = data.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let data = data.iter();
+    data.filter(|d| can_be_calculated(d, alternative_operators)).map(|(test_value, _)| *test_value).sum()
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    const PART_1_MESSAGE: &'static str = "Total calibration result";
+    const PART_2_MESSAGE: &'static str = "Total calibration result";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        input.lines().map(|line| {
+            let equation: Equation = key_values(input, line)?;
+            if equation.1.is_empty() { return Err(AocError::at(input, line, "expected at least one number")); }
+            Ok(equation)
+        }).collect()
+    }
+
+    fn part1(&self, data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(total_calibration_result(data, &[Operator::Multiply]).into())
+    }
+
+    fn part2(&self, data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(total_calibration_result(data, &[Operator::Multiply, Operator::Concatenate]).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_07.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day07.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(3749)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day07.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(11387)));
+    }
+
+    #[test]
+    fn overflowing_equation() {
+        let input = "1: 999 999 999 999 999 999 999 999\n7: 3 4";
+        assert_eq!(Day07.parse_and_solve(input, Part::One), Ok(Answer::Number(7)));
+        assert_eq!(Day07.parse_and_solve(input, Part::Two), Ok(Answer::Number(7)));
+    }
+
+    #[test]
+    fn equation_without_numbers() {
+        assert!(Day07.parse("7: 3 4\n5:").is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use std::cmp::max;
+use crate::cancel;
+use crate::error::AocError;
+use crate::range_set::RangeSet;
+use crate::solution::{Answer, Part, Solution, Variant};
+
+pub struct Day09;
+
+type Disk = Vec<Option<u64>>;
+
+fn parse_disk_map(disk_map: &[u32]) -> Disk {
+    let mut disk: Disk = Vec::new();
+    let mut file = true;
+    let mut file_nr = 0;
+    for &n in disk_map {
+        if file {
+            for _ in 0..n { disk.push(Some(file_nr)); }
+            file_nr += 1;
+        } else {
+            for _ in 0..n { disk.push(None); }
+        }
+        file = !file;
+    }
+    disk
+}
+
+fn compress_disk_blocks(disk: &Disk) -> Disk {
+    let mut new_disk = disk.clone();
+    let mut i = 0;
+    let mut j = new_disk.len().saturating_sub(1);
+    loop {
+        while i < j && new_disk[i].is_some() { i += 1; }
+        while i < j && new_disk[j].is_none() { j -= 1; }
+        if i >= j { break; }
+        new_disk.swap(i, j);
+    }
+    new_disk
+}
+
+// `None` for a file without any blocks
+fn get_file_start_end(disk: &Disk, file_id: u64) -> Result<Option<(usize, usize)>, &str> {
+    let start_opt = disk.iter().position(|&id| id == Some(file_id));
+    let end_opt = disk.iter().rposition(|&id| id == Some(file_id));
+    let (start, end) = match (start_opt, end_opt) {
+        (Some(start), Some(end)) => (start, end),
+        _ => { return Ok(None); },
+    };
+    if disk[start..(end + 1)].iter().any(|&id| id != Some(file_id)) {
+        return Err("file fragmented");
+    }
+    Ok(Some((start, end)))
+}
+
+fn swap_file(disk: &mut Disk, old_start: usize, old_end: usize, new_start: usize) -> Result<(), &str> {
+    let file_len = old_end - old_start + 1;
+    if disk[new_start..(new_start + file_len)].iter().any(|id| id.is_some()) {
+        return Err("attempting to insert into existing file");
+    }
+
+    let split_point = max(old_start, new_start);
+    let (left, right) = disk.split_at_mut(split_point);
+    let left_slice_start = if split_point == old_start { new_start } else { old_start };
+    left[left_slice_start..(left_slice_start + file_len)].swap_with_slice(&mut right[..file_len]);
+
+    Ok(())
+}
+
+fn find_free_space(disk: &Disk, size: usize) -> Option<usize> {
+    let mut free_space_counter = 0;
+    let mut start = 0;
+    for (i, id) in disk.iter().enumerate() {
+        match id {
+            Some(_) => { free_space_counter = 0; },
+            None => {
+                if free_space_counter == 0 { start = i; }
+                free_space_counter += 1;
+            }
+        }
+        if free_space_counter == size {
+            return Some(start)
+        }
+    }
+    None
+}
+
+fn compress_disk_files(disk: &Disk) -> Result<Disk, AocError> {
+    let mut disk = disk.clone();
+    let Some(max_file_id) = disk.iter().filter_map(|e| *e).max() else { return Ok(disk); };
+    let token = cancel::current();
+    for moved in 0..=max_file_id {
+        // every file scans the whole disk, so this is checked for each of them
+        token.check()?;
+        token.set_progress(format_args!("{moved} of {} files moved", max_file_id + 1));
+        let file_id = max_file_id - moved;
+        let Some((file_start, file_end)) = get_file_start_end(&disk, file_id).map_err(AocError::new)? else { continue; };
+        let file_size = file_end - file_start + 1;
+        if let Some(free_space_start) = find_free_space(&disk, file_size) {
+            if free_space_start < file_start {
+                swap_file(&mut disk, file_start, file_end, free_space_start).map_err(AocError::new)?;
+            }
+        }
+    }
+    Ok(disk)
+}
+
+// Moves whole files as a list of (file id, position, length), with the free space kept in a range set rather than
+// scanned for block by block
+fn compress_file_list(disk_map: &[u32]) -> Vec<(u64, usize, usize)> {
+    let mut files = Vec::new();
+    let mut free_space = RangeSet::new();
+    let mut position = 0;
+    for (i, &n) in disk_map.iter().enumerate() {
+        let n = n as usize;
+        if i % 2 == 0 { files.push(((i / 2) as u64, position, n)); } else { free_space.insert(position..position + n); }
+        position += n;
+    }
+    for (_, position, len) in files.iter_mut().rev() {
+        if let Some(gap) = free_space.first_fit(*len, *position) {
+            free_space.remove(gap.start..gap.start + *len);
+            free_space.insert(*position..*position + *len);
+            *position = gap.start;
+        }
+    }
+    files
+}
+
+fn calculate_checksum(disk: &Disk) -> u64 {
+    disk
+        .iter()
+        .enumerate()
+        .map(|(i, &id)| {
+            if let Some(id_val) = id { (i as u64) * id_val } else { 0 }
+        })
+        .sum()
+}
+
+impl Solution for Day09 {
+    type Input = Vec<u32>;
+
+    const PART_1_MESSAGE: &'static str = "Disk checksum";
+    const PART_2_MESSAGE: &'static str = "Disk checksum";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let line = input.lines().next().ok_or("empty input")?;
+        let disk_map: Vec<u32> = line
+            .char_indices()
+            .map(|(i, c)| char::to_digit(c, 10).ok_or_else(|| AocError::at(input, &line[i..i + c.len_utf8()], "invalid digit")))
+            .collect::<Result<_, _>>()?;
+        if disk_map.iter().all(|&n| n == 0) {
+            return Err(AocError::at(input, line, "the disk is empty"));
+        }
+        Ok(disk_map)
+    }
+
+    fn part1(&self, disk_map: &Self::Input) -> Result<Answer, AocError> {
+        let starting_disk: Disk = parse_disk_map(disk_map);
+        let compressed_disk: Disk = compress_disk_blocks(&starting_disk);
+        Ok(calculate_checksum(&compressed_disk).into())
+    }
+
+    fn part2(&self, disk_map: &Self::Input) -> Result<Answer, AocError> {
+        let files = compress_file_list(disk_map);
+        let checksum: u64 = files.iter().map(|&(id, position, len)| (position..position + len).map(|p| id * p as u64).sum::<u64>()).sum();
+        Ok(checksum.into())
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![Variant {
+            part: Part::Two,
+            name: "block_scan",
+            solve: |_, disk_map| {
+                let starting_disk: Disk = parse_disk_map(disk_map);
+                let compressed_disk: Disk = compress_disk_files(&starting_disk)?;
+                Ok(calculate_checksum(&compressed_disk).into())
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::DynSolution;
+
+    const EXAMPLE: &str = include_str!("examples/day_09.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day09.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(1928)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day09.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(2858)));
+    }
+
+    #[test]
+    fn part_2_block_scan() {
+        let input = Day09.parse_input(EXAMPLE).unwrap();
+        assert_eq!(Day09.solve_variant(input.as_ref(), Part::Two, "block_scan"), Ok(Answer::Number(2858)));
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert!(Day09.parse("").is_err());
+        assert!(Day09.parse("000").is_err());
+        // zero-length files, and disks without (or with only) free space
+        for (disk_map, part_1, part_2) in [("1203112", 15, 15), ("3", 0, 0), ("05", 0, 0), ("0213", 0, 0)] {
+            let input = Day09.parse_input(disk_map).unwrap();
+            assert_eq!(Day09.solve(input.as_ref(), Part::One), Ok(Answer::Number(part_1)));
+            assert_eq!(Day09.solve(input.as_ref(), Part::Two), Ok(Answer::Number(part_2)));
+            assert_eq!(Day09.solve_variant(input.as_ref(), Part::Two, "block_scan"), Ok(Answer::Number(part_2)));
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use crate::cancel::{self, CancellationToken};
+use crate::progress;
+use crate::error::AocError;
+use crate::math;
+use crate::params::{parse_value, unknown_param, Param};
+use crate::parsing::space_list;
+use crate::solution::{Answer, Solution};
+
+pub struct Day11 {
+    pub n_blinks_part_1: usize,
+    pub n_blinks_part_2: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self { n_blinks_part_1: 25, n_blinks_part_2: 75 }
+    }
+}
+
+type StoneType = u64;
+type StoneCounts = HashMap<StoneType, u64>;
+
+fn split_num(num: StoneType) -> Result<(StoneType, StoneType), ()> {
+    let digits = math::digit_count(num);
+    if digits % 2 == 1 { return Err(()); }
+    Ok(math::split_digits(num, digits / 2))
+}
+
+// Each split shifts the rest of the list, so a blink can take a long time once there are millions of stones
+fn blink(stones: &mut Vec<StoneType>, token: &CancellationToken) -> Result<(), AocError> {
+    let mut i = 0;
+    while i < stones.len() {
+        if i % 100_000 == 0 { token.check()?; }
+        match split_num(stones[i]) {
+            Ok((left, right)) => {
+                stones[i] = right;
+                stones.insert(i, left);
+                i += 1;
+            },
+            Err(_) => {
+                stones[i] = if stones[i] == 0 { 1 } else { stones[i] * 2024 };
+            },
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+fn insert_or_add<K: Eq + Hash + Copy>(map: &mut HashMap<K, u64>, key: &K, value: &u64) -> Result<(), AocError> {
+    let old_value = map.get(key).unwrap_or(&0);
+    map.insert(*key, value.checked_add(*old_value).ok_or("too many stones to count")?);
+    Ok(())
+}
+
+fn blink_counts(stones: StoneCounts) -> Result<StoneCounts, AocError> {
+    let mut new_stones = StoneCounts::new();
+    for (stone, old_count) in &stones {
+        let count = *old_count;
+        match split_num(*stone) {
+            Ok((left, right)) => {
+                insert_or_add(&mut new_stones, &left, &count)?;
+                insert_or_add(&mut new_stones, &right, &count)?;
+            },
+            Err(_) => {
+                let new_s = if *stone == 0 { 1 } else { stone * 2024 };
+                insert_or_add(&mut new_stones, &new_s, &count)?;
+            },
+        };
+    }
+    Ok(new_stones)
+}
+
+impl Solution for Day11 {
+    type Input = Vec<StoneType>;
+
+    const PART_1_MESSAGE: &'static str = "Number of stones";
+    const PART_2_MESSAGE: &'static str = "Number of stones";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let line = input.lines().next().ok_or("empty input")?;
+        space_list(input, line)
+    }
+
+    fn part1(&self, stones: &Self::Input) -> Result<Answer, AocError> {
+        let mut stones = stones.clone();
+        let token = cancel::current();
+        for i in 0..self.n_blinks_part_1 {
+            token.set_progress(format_args!("{i} blinks"));
+            blink(&mut stones, &token)?;
+        }
+        stones.len().try_into()
+    }
+
+    fn part2(&self, stones: &Self::Input) -> Result<Answer, AocError> {
+        let mut stones = stones.iter().try_fold(StoneCounts::new(), |mut counts, s| {
+            insert_or_add(&mut counts, s, &1)?;
+            Ok::<_, AocError>(counts)
+        })?;
+        for i in 0..self.n_blinks_part_2 {
+            stones = blink_counts(stones)?;
+            progress::debug(format_args!("Blinked {} times", i+1));
+        }
+        let mut total: u64 = 0;
+        for v in stones.values() {
+            total = total.checked_add(*v).ok_or("too many stones to count")?;
+        }
+        Ok(total.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        let n_blinks = if self.n_blinks_part_1 == self.n_blinks_part_2 {
+            self.n_blinks_part_1.to_string()
+        } else {
+            format!("{} in part 1, {} in part 2", self.n_blinks_part_1, self.n_blinks_part_2)
+        };
+        vec![
+            Param::new("n_blinks", n_blinks, "number of blinks in both parts"),
+            Param::new("n_blinks_part_1", self.n_blinks_part_1, "number of blinks in part 1"),
+            Param::new("n_blinks_part_2", self.n_blinks_part_2, "number of blinks in part 2"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), AocError> {
+        match name {
+            "n_blinks" => {
+                self.n_blinks_part_1 = parse_value(name, value)?;
+                self.n_blinks_part_2 = self.n_blinks_part_1;
+            }
+            "n_blinks_part_1" => self.n_blinks_part_1 = parse_value(name, value)?,
+            "n_blinks_part_2" => self.n_blinks_part_2 = parse_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_11.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day11::default().parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(55312)));
+    }
+
+    // no answer is published for 75 blinks of the example, so part 2 is checked with the 25 of part 1
+    #[test]
+    fn part_2_example() {
+        let day = Day11 { n_blinks_part_2: 25, ..Default::default() };
+        assert_eq!(day.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(55312)));
+    }
+
+    #[test]
+    fn n_blinks_sets_both_parts() {
+        let mut day = Day11::default();
+        assert_eq!(day.params()[0].value, "25 in part 1, 75 in part 2");
+        day.set_param("n_blinks", "100").unwrap();
+        assert_eq!((day.n_blinks_part_1, day.n_blinks_part_2), (100, 100));
+        assert_eq!(day.params()[0].value, "100");
+    }
+
+    #[test]
+    fn too_many_stones() {
+        // 100 blinks of the example still fit in a u64, 110 don't
+        let day = Day11 { n_blinks_part_2: 100, ..Default::default() };
+        assert_eq!(day.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(2_266_558_877_486_382_721)));
+        let day = Day11 { n_blinks_part_2: 110, ..Default::default() };
+        assert_eq!(day.parse_and_solve(EXAMPLE, Part::Two), Err(AocError::new("too many stones to count")));
+    }
+}
@@ -1,16 +1,13 @@
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
 use std::iter::zip;
 use itertools::{Itertools, repeat_n};
-use aoc_2024_rust::coord_grid::{Direction, file_lines_to_char_grid, Grid};
+use crate::coord_grid::{Direction, str_to_char_grid, Grid};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 
-const OUTPUT_MESSAGE: &str = "Total cost";
-const INPUT_FILE: &str = "input/day_12.txt";
+pub struct Day12;
 
-const MAP_WIDTH: usize = 140;
-const MAP_HEIGHT: usize = 140;
-type GardenGrid = Grid<MAP_WIDTH, MAP_HEIGHT, char>;
+type GardenGrid = Grid<char>;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 struct Region {
@@ -40,7 +37,26 @@ impl Region {
         self.plots.len()
     }
 
-    fn calculate_n_sides(&self) -> usize {
+    fn calculate_perimeter(&self, grid: &GardenGrid) -> usize {
+        let directions = Direction::main_directions();
+        let potential_perimeter_coords: Vec<(isize, isize)> =
+            self
+                .plots
+                .iter()
+                .flat_map(|plot| {
+                    directions.iter().map(|direction| grid.force_move_coords(*plot, *direction))
+                }).collect();
+        // note: potential_perimeter_coords will contain the same plot multiple times,
+        // e.g. around corners. This is intended, as these should be counted twice
+        // (once from each side touching the current region),
+        // and this is easier than trying to implement squares "between" each plot.
+        potential_perimeter_coords.iter().filter(|&plot|
+            ! grid.contains_coords_signed(*plot)
+                || ! self.plots.contains(&(plot.0.try_into().unwrap(), plot.1.try_into().unwrap()))
+        ).count()
+    }
+
+    fn calculate_n_sides(&self, grid: &GardenGrid) -> usize {
         // move "ruler" over grid vertically and horizontally,
         // and detect whenever the "ruler" covers one or more sides of the region
         let mut num_sides = 0;
@@ -63,14 +79,13 @@ impl Region {
                     range_width.clone().map(|j| self.plots.contains(&(i-1, j))).collect()
                 };
             let is_region_below: Vec<bool> =
-                if i == MAP_HEIGHT {
+                if i == grid.height() {
                     repeat_n(false, width).collect()
                 } else {
                     range_width.clone().map(|j| self.plots.contains(&(i, j))).collect()
                 };
             let is_boundary_1: Vec<bool> = zip(is_region_above.clone(), is_region_below.clone()).map(|(a, b)| a && !b).collect();
             let is_boundary_2: Vec<bool> = zip(is_region_above.clone(), is_region_below.clone()).map(|(a, b)| (!a) && b).collect();
-            //println!("{:?}", zip(is_boundary_1.clone(), is_boundary_2.clone()).map(|(b1, b2)| if b1 { '^' } else if b2 { 'v' } else { ' ' }).collect::<String>());
             let num_sides_in_row_1 = is_boundary_1
                 .iter()
                 .coalesce(|current, previous| if *current == *previous { Ok(current) } else { Err((current, previous)) })
@@ -92,7 +107,7 @@ impl Region {
                     range_height.clone().map(|i| self.plots.contains(&(i, j-1))).collect()
                 };
             let is_region_right: Vec<bool> =
-                if j == MAP_WIDTH {
+                if j == grid.width() {
                     repeat_n(false, height).collect()
                 } else {
                     range_height.clone().map(|i| self.plots.contains(&(i, j))).collect()
@@ -115,25 +130,89 @@ impl Region {
     }
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<u32, ()> {
-    let grid: GardenGrid = file_lines_to_char_grid(lines).expect("unable to read input");
-    let mut covered_plots = [[false; MAP_WIDTH]; MAP_HEIGHT];
+fn find_regions(grid: &GardenGrid) -> Vec<Region> {
+    let mut covered_plots: Grid<bool> = Grid::create(grid.width(), grid.height());
     let mut regions: Vec<Region> = Vec::new();
     for plot in grid.iter_coords() {
-        if covered_plots[plot.0][plot.1] { continue; }
-        let new_region = Region::create_from_plot(plot, &grid);
-        new_region.plots.iter().for_each(|plot| covered_plots[plot.0][plot.1] = true);
+        if covered_plots.get(plot).unwrap() { continue; }
+        let new_region = Region::create_from_plot(plot, grid);
+        new_region.plots.iter().for_each(|&plot| covered_plots.set(plot, true).unwrap());
         regions.push(new_region);
     }
-    let total = regions.iter().map(|r| u32::try_from(r.calculate_area() * r.calculate_n_sides()).unwrap()).sum();
-    Ok(total)
-    // 5_498_242 too high!
-    // 5_389 too low!
+    regions
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+impl Solution for Day12 {
+    type Input = GardenGrid;
+
+    const PART_1_MESSAGE: &'static str = "Total cost";
+    const PART_2_MESSAGE: &'static str = "Total cost";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        str_to_char_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let regions = find_regions(grid);
+        let total = regions.iter().map(|r| u32::try_from(r.calculate_area() * r.calculate_perimeter(grid)).unwrap()).sum::<u32>();
+        Ok(total.into())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let regions = find_regions(grid);
+        let total = regions.iter().map(|r| u32::try_from(r.calculate_area() * r.calculate_n_sides(grid)).unwrap()).sum::<u32>();
+        Ok(total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_12.txt");
+    const EXAMPLE_SMALL: &str = include_str!("examples/day_12_small.txt");
+    const EXAMPLE_ENCLOSED: &str = include_str!("examples/day_12_enclosed.txt");
+    const EXAMPLE_E_SHAPE: &str = include_str!("examples/day_12_e_shape.txt");
+    const EXAMPLE_DIAGONAL: &str = include_str!("examples/day_12_diagonal.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(1930)));
+    }
+
+    #[test]
+    fn part_1_example_small() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE_SMALL, Part::One), Ok(Answer::Number(140)));
+    }
+
+    #[test]
+    fn part_1_example_enclosed() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE_ENCLOSED, Part::One), Ok(Answer::Number(772)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(1206)));
+    }
+
+    #[test]
+    fn part_2_example_small() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE_SMALL, Part::Two), Ok(Answer::Number(80)));
+    }
+
+    #[test]
+    fn part_2_example_enclosed() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE_ENCLOSED, Part::Two), Ok(Answer::Number(436)));
+    }
+
+    #[test]
+    fn part_2_example_e_shape() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE_E_SHAPE, Part::Two), Ok(Answer::Number(236)));
+    }
+
+    #[test]
+    fn part_2_example_diagonal() {
+        assert_eq!(Day12.parse_and_solve(EXAMPLE_DIAGONAL, Part::Two), Ok(Answer::Number(368)));
+    }
 }
@@ -0,0 +1,113 @@
+use regex::Regex;
+use crate::error::AocError;
+use crate::params::{parse_value, unknown_param, Param};
+use crate::parsing::{captures, split_sections};
+use crate::solution::{Answer, Solution};
+
+pub struct Day13 {
+    pub max_presses_per_button: usize,
+    pub button_a_cost: u64,
+    pub button_b_cost: u64,
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Self { max_presses_per_button: 100, button_a_cost: 3, button_b_cost: 1 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    button_a: (usize, usize),
+    button_b: (usize, usize),
+    prize: (usize, usize),
+}
+
+impl ClawMachine {
+    fn find_all_solutions(&self, max_presses: usize) -> Vec<(usize, usize)> {
+        let mut solutions = Vec::new();
+        for a in 0..max_presses {
+            for b in 0..max_presses {
+                let position = (self.button_a.0 * a + self.button_b.0 * b, self.button_a.1 * a + self.button_b.1 * b);
+                if position == self.prize {
+                    solutions.push((a, b));
+                } else if position.0 >= self.prize.0 || position.1 >= self.prize.1 {
+                    break
+                }
+            }
+        }
+        solutions
+    }
+
+    fn get_solution_cost(&self, solution: &(usize, usize), rules: &Day13) -> u64 {
+        u64::try_from(solution.0).unwrap() * rules.button_a_cost
+            + u64::try_from(solution.1).unwrap() * rules.button_b_cost
+    }
+
+    fn find_minimum_cost(&self, rules: &Day13) -> Option<u64> {
+        let solutions = self.find_all_solutions(rules.max_presses_per_button);
+        let costs = solutions.iter().map(|s| self.get_solution_cost(s, rules)).collect::<Vec<u64>>();
+        costs.iter().cloned().min()
+    }
+}
+
+fn parse_single_claw_machine(input: &str, section: &str) -> Result<ClawMachine, AocError> {
+    let button_regex = Regex::new(r"Button \w: X\+(\d+), Y\+(\d+)").unwrap();
+    let prize_regex = Regex::new(r"Prize: X=(\d+), Y=(\d+)").unwrap();
+    let mut lines = section.lines();
+    let mut parse_line = |regex: &Regex, description: &str| -> Result<(usize, usize), AocError> {
+        let line = lines.next().ok_or_else(|| AocError::at(input, section, format!("missing {description}")))?;
+        captures(input, regex, line)
+    };
+    Ok(ClawMachine {
+        button_a: parse_line(&button_regex, "button A")?,
+        button_b: parse_line(&button_regex, "button B")?,
+        prize: parse_line(&prize_regex, "prize")?,
+    })
+}
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+
+    const PART_1_MESSAGE: &'static str = "Minimum nr. of tokens";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        split_sections(input).into_iter().map(|section| parse_single_claw_machine(input, section)).collect()
+    }
+
+    fn part1(&self, claw_machines: &Self::Input) -> Result<Answer, AocError> {
+        let total = claw_machines.iter().filter_map(|cm| cm.find_minimum_cost(self)).sum::<u64>();
+        Ok(total.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("max_presses_per_button", self.max_presses_per_button, "maximum number of presses of each button"),
+            Param::new("button_a_cost", self.button_a_cost, "tokens needed to press button A"),
+            Param::new("button_b_cost", self.button_b_cost, "tokens needed to press button B"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), AocError> {
+        match name {
+            "max_presses_per_button" => self.max_presses_per_button = parse_value(name, value)?,
+            "button_a_cost" => self.button_a_cost = parse_value(name, value)?,
+            "button_b_cost" => self.button_b_cost = parse_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_13.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day13::default().parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(480)));
+    }
+}
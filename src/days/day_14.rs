@@ -0,0 +1,259 @@
+use itertools::Itertools;
+use regex::Regex;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use crate::cancel;
+use crate::coord_grid::Grid;
+use crate::progress::{self, Verbosity};
+use crate::error::AocError;
+use crate::math;
+use crate::params::{parse_value, unknown_param, Param};
+use crate::parsing::captures;
+use crate::simulation::{self, Simulation};
+use crate::solution::{Answer, Solution};
+use crate::trace::{self, Event};
+
+pub struct Day14 {
+    pub area_width: usize,
+    pub area_height: usize,
+    pub n_seconds_part_1: usize,
+    // defaults to the period after which the robots' positions repeat when not set
+    pub max_seconds_part_2: Option<usize>,
+    // a row containing this many robots side by side is taken to be part of the picture
+    pub picture_row_length: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self { area_width: 101, area_height: 103, n_seconds_part_1: 100, max_seconds_part_2: None, picture_row_length: 15 }
+    }
+}
+
+type RobotGrid = Grid<bool>;
+
+#[derive(Hash, Debug, Copy, Clone, Eq, PartialEq, EnumIter)]
+enum Quadrant {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    location: (usize, usize),
+    velocity: (isize, isize),
+}
+
+impl Robot {
+    // p is less than p_max, and p_max is the size of an allocated grid so it fits in an isize
+    fn next_1d_coord(p: usize, v: isize, p_max: usize) -> usize {
+        let step = v.rem_euclid(isize::try_from(p_max).unwrap_or(isize::MAX)).unsigned_abs();
+        (p + step) % p_max
+    }
+
+    fn tick(&mut self, area_width: usize, area_height: usize) {
+        self.location = (
+            Self::next_1d_coord(self.location.0, self.velocity.0, area_width),
+            Self::next_1d_coord(self.location.1, self.velocity.1, area_height),
+        )
+    }
+
+    fn get_quadrant(&self, area_width: usize, area_height: usize) -> Option<Quadrant> {
+        if self.location.0 == area_width / 2 || self.location.1 == area_height / 2 {
+            None
+        } else {
+            let left = self.location.0 < area_width / 2;
+            let top = self.location.1 < area_height / 2;
+            Some(
+                match (top, left) {
+                    (true, true) => Quadrant::TopLeft,
+                    (true, false) => Quadrant::TopRight,
+                    (false, true) => Quadrant::BottomLeft,
+                    (false, false) => Quadrant::BottomRight,
+                }
+            )
+        }
+    }
+}
+
+// All robots moving at once - their positions repeat after at most (area width * area height) seconds
+#[derive(Clone)]
+struct Area {
+    robots: Vec<Robot>,
+    width: usize,
+    height: usize,
+}
+
+impl Simulation for Area {
+    type Key = Vec<(usize, usize)>;
+
+    fn step(&mut self) {
+        self.robots.iter_mut().for_each(|r| r.tick(self.width, self.height));
+        trace::step();
+        trace_positions(&self.robots);
+    }
+
+    fn state_key(&self) -> Self::Key {
+        self.robots.iter().map(|r| r.location).collect()
+    }
+}
+
+fn print_grid(grid: &RobotGrid) {
+    if !progress::is_enabled(Verbosity::Normal) { return; }
+    grid.iter_rows().for_each(|r| {
+        progress::info(r.iter().map(|&v| if v { "█" } else { " " }).collect::<String>());
+    });
+}
+
+// Robots can share tiles, so the grid is recorded empty and their positions separately
+fn trace_start(robots: &[Robot], area_width: usize, area_height: usize) {
+    trace::record(|| Event::Grid { rows: vec![".".repeat(area_width); area_height] });
+    trace_positions(robots);
+}
+
+fn trace_positions(robots: &[Robot]) {
+    trace::record(|| Event::Positions { entity: String::from("robot"), at: robots.iter().map(|r| (r.location.1, r.location.0)).collect() });
+}
+
+fn contains_picture(grid: &RobotGrid, picture_row_length: usize) -> bool {
+    grid.iter_rows().any(|r| {
+        r.iter()
+            .chunk_by(|&v| *v)
+            .into_iter()
+            .any(|(v, run)| v && run.count() > picture_row_length)
+    })
+}
+
+impl Day14 {
+    // every robot is back where it started after a multiple of both the width and the height, so there's no point
+    // in searching any further
+    fn max_seconds_part_2(&self) -> usize {
+        let period = math::lcm(self.area_width, self.area_height).unwrap_or(usize::MAX);
+        self.max_seconds_part_2.unwrap_or(period).min(period)
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    const PART_1_MESSAGE: &'static str = "Safety factor";
+    const PART_2_MESSAGE: &'static str = "Seconds until picture appears";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let robot_regex  = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
+        input.lines().map(|line| {
+            let (p_x, p_y, v_x, v_y) = captures::<(usize, usize, isize, isize)>(input, &robot_regex, line)?;
+            if p_x >= self.area_width || p_y >= self.area_height {
+                let position = line.split_whitespace().next().unwrap_or(line);
+                return Err(AocError::at(input, position, format!("position lies outside the {}x{} area", self.area_width, self.area_height)));
+            }
+            Ok(Robot {
+                location: (p_x, p_y),
+                velocity: (v_x, v_y),
+            })
+        }).collect()
+    }
+
+    fn part1(&self, robots: &Self::Input) -> Result<Answer, AocError> {
+        let mut area = Area { robots: robots.clone(), width: self.area_width, height: self.area_height };
+        trace_start(&area.robots, self.area_width, self.area_height);
+        if let Some(cycle) = simulation::fast_forward(&mut area, self.n_seconds_part_1)? {
+            progress::debug(format_args!("Positions repeat every {} seconds", cycle.period));
+        }
+        let robots_per_quadrant = area.robots.iter().filter_map(|r| r.get_quadrant(self.area_width, self.area_height)).counts();
+        let safety_factor = Quadrant::iter().map(|q| *robots_per_quadrant.get(&q).unwrap_or(&0)).product::<usize>();
+        safety_factor.try_into()
+    }
+
+    fn part2(&self, robots: &Self::Input) -> Result<Answer, AocError> {
+        let mut robots = robots.clone();
+        let mut grid = RobotGrid::create(self.area_width, self.area_height);
+        let token = cancel::current();
+        trace_start(&robots, self.area_width, self.area_height);
+        let max_seconds = self.max_seconds_part_2();
+        for i in 1..=max_seconds {
+            token.check()?;
+            token.set_progress(format_args!("{i} seconds"));
+            grid.set_all(false);
+            for r in robots.iter_mut() {
+                r.tick(self.area_width, self.area_height);
+                grid.set((r.location.1, r.location.0), true).map_err(|_| "robot outside the area")?;
+            }
+            trace::step();
+            trace_positions(&robots);
+            if contains_picture(&grid, self.picture_row_length) {
+                print_grid(&grid);
+                return i.try_into();
+            }
+            progress::debug(format_args!("{i} seconds passed."));
+        }
+        Err(AocError::new(format!("no picture found within {max_seconds} seconds")))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("area_width", self.area_width, "width of the area the robots move in"),
+            Param::new("area_height", self.area_height, "height of the area the robots move in"),
+            Param::new("n_seconds_part_1", self.n_seconds_part_1, "number of seconds simulated in part 1"),
+            Param::new("max_seconds_part_2", self.max_seconds_part_2(), "number of seconds after which part 2 gives up"),
+            Param::new("picture_row_length", self.picture_row_length, "robots in a row needed to detect the picture"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), AocError> {
+        match name {
+            "area_width" => self.area_width = parse_value(name, value)?,
+            "area_height" => self.area_height = parse_value(name, value)?,
+            "n_seconds_part_1" => self.n_seconds_part_1 = parse_value(name, value)?,
+            "max_seconds_part_2" => self.max_seconds_part_2 = Some(parse_value(name, value)?),
+            "picture_row_length" => self.picture_row_length = parse_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_14.txt");
+    // (the example is too small to contain the picture from part 2)
+
+    fn example_day() -> Day14 {
+        Day14 { area_width: 11, area_height: 7, ..Day14::default() }
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(example_day().parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(12)));
+    }
+
+    #[test]
+    fn part_2_searches_one_period() {
+        assert_eq!(example_day().parse_and_solve(EXAMPLE, Part::Two), Err(AocError::new("no picture found within 77 seconds")));
+        let day = Day14 { max_seconds_part_2: Some(1000), ..example_day() };
+        assert_eq!(day.max_seconds_part_2(), 77);
+        let day = Day14 { max_seconds_part_2: Some(10), ..example_day() };
+        assert_eq!(day.max_seconds_part_2(), 10);
+    }
+
+    #[test]
+    fn positions_outside_area() {
+        let day = Day14 { area_width: 5, area_height: 5, ..Day14::default() };
+        let error = day.parse("p=0,4 v=3,-3\np=6,3 v=-1,-3").unwrap_err();
+        assert_eq!(error.to_string(), "position lies outside the 5x5 area (line 2, column 1)");
+    }
+
+    #[test]
+    fn fast_robots() {
+        // speeds larger than the area wrap around as many times as needed
+        let day = Day14 { area_width: 3, area_height: 3, ..Day14::default() };
+        let robots = day.parse("p=1,1 v=-7,302\np=0,0 v=3,3").unwrap();
+        let mut area = Area { robots, width: 3, height: 3 };
+        area.step();
+        assert_eq!(area.state_key(), [(0, 0), (0, 0)]);
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::{HashMap, HashSet};
+use crate::coord_grid::{Direction, Grid, str_to_grid};
+use crate::progress::{self, Verbosity};
+use crate::error::AocError;
+use crate::parsing::exact_sections;
+use crate::solution::{Answer, Solution};
+use crate::trace::{self, Event};
+
+pub struct Day15;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum FactoryTile {
+    #[default]
+    None,
+    Wall,
+    Box,
+    Robot,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+enum WideFactoryTile {
+    #[default]
+    None,
+    Wall,
+    BoxLeft,
+    BoxRight,
+    Robot,
+}
+
+impl FactoryTile {
+    fn to_char(self) -> char {
+        match self {
+            FactoryTile::None => '.',
+            FactoryTile::Wall => '#',
+            FactoryTile::Box => 'O',
+            FactoryTile::Robot => '@',
+        }
+    }
+}
+
+impl WideFactoryTile {
+    fn to_char(self) -> char {
+        match self {
+            WideFactoryTile::None => '.',
+            WideFactoryTile::Robot => '@',
+            WideFactoryTile::Wall => '#',
+            WideFactoryTile::BoxLeft => '[',
+            WideFactoryTile::BoxRight => ']',
+        }
+    }
+}
+
+fn entity_name(is_robot: bool) -> String {
+    String::from(if is_robot { "robot" } else { "box" })
+}
+
+fn trace_move(is_robot: bool, from: (usize, usize), to: (usize, usize)) {
+    trace::record(|| Event::Move { entity: entity_name(is_robot), from, to });
+}
+
+fn trace_blocked(at: (usize, usize), direction: Direction) {
+    trace::record(|| Event::Blocked { entity: entity_name(true), at, direction });
+}
+
+type FactoryMap = Grid<FactoryTile>;
+type WideFactoryMap = Grid<WideFactoryTile>;
+
+type Move = ((usize, usize), (usize, usize));
+
+enum MoveError {
+    MoreMovesRequired(Vec<Move>),
+    Impossible,
+    // the map is in a state the puzzle doesn't allow for
+    Invalid(&'static str),
+}
+
+type MoveRequirements = HashMap<Move, Vec<Move>>;
+
+fn move_robot(direction: &Direction, factory_map: &mut FactoryMap) -> Result<(), AocError> {
+    let robot_position = factory_map.position(FactoryTile::Robot).ok_or("unable to find robot")?;
+    trace::step();
+    match recurse(direction, factory_map, robot_position) {
+        Ok(new_map) => *factory_map = new_map,
+        Err(MoveError::Invalid(message)) => return Err(AocError::new(message)),
+        Err(_) => trace_blocked(robot_position, *direction),
+    }
+    Ok(())
+}
+
+fn recurse(direction: &Direction, factory_map: &FactoryMap, position_to_check: (usize, usize)) -> Result<FactoryMap, MoveError> {
+    if let Ok(new_coords) = factory_map.move_coords(position_to_check, *direction) {
+        match factory_map.get(new_coords).unwrap() {
+            FactoryTile::None => {
+                let mut new_map = factory_map.clone();
+                let old_value = new_map.get(position_to_check).unwrap();
+                let _ = new_map.set(new_coords, old_value);
+                let _ = new_map.set(position_to_check, FactoryTile::None);
+                trace_move(old_value == FactoryTile::Robot, position_to_check, new_coords);
+                Ok(new_map)
+            }
+            FactoryTile::Wall => Err(MoveError::Impossible),
+            FactoryTile::Robot => Err(MoveError::Invalid("more than one robot on the map")),
+            FactoryTile::Box => {
+                let mut new_map = recurse(direction, factory_map, new_coords)?;
+                let old_value = new_map.get(position_to_check).unwrap();
+                let _ = new_map.set(new_coords, old_value);
+                let _ = new_map.set(position_to_check, FactoryTile::None);
+                trace_move(old_value == FactoryTile::Robot, position_to_check, new_coords);
+                Ok(new_map)
+            }
+        }
+    } else { Err(MoveError::Impossible) }
+}
+
+fn calculate_total_gps_value(factory_map: &FactoryMap) -> usize {
+    factory_map.iter_coords().filter_map(|(i, j)| match factory_map.get((i, j)).unwrap() { FactoryTile::Box => Some(100 * i + j), _ => None }).sum()
+}
+
+fn widen_map(factory_map: &FactoryMap) -> WideFactoryMap {
+    let mut wide_map = WideFactoryMap::create(factory_map.width() * 2, factory_map.height());
+    factory_map.iter_coords().for_each(|(i, j)| {
+        let tile_types = match factory_map.get((i, j)).unwrap() {
+            FactoryTile::None => (WideFactoryTile::None, WideFactoryTile::None),
+            FactoryTile::Wall => (WideFactoryTile::Wall, WideFactoryTile::Wall),
+            FactoryTile::Box => (WideFactoryTile::BoxLeft, WideFactoryTile::BoxRight),
+            FactoryTile::Robot => (WideFactoryTile::Robot, WideFactoryTile::None),
+        };
+        let _ = wide_map.set((i, j*2), tile_types.0);
+        let _ = wide_map.set((i, j*2 + 1), tile_types.1);
+    });
+    wide_map
+}
+
+fn move_robot_wide(direction: &Direction, factory_map: &mut WideFactoryMap) -> Result<(), AocError> {
+    let robot_position = factory_map.position(WideFactoryTile::Robot).ok_or("unable to find robot")?;
+    trace::step();
+    match push(direction, factory_map, robot_position) {
+        Ok(new_map) => *factory_map = new_map,
+        Err(MoveError::Invalid(message)) => return Err(AocError::new(message)),
+        Err(_) => trace_blocked(robot_position, *direction),
+    }
+    Ok(())
+}
+
+fn push(direction: &Direction, factory_map: &WideFactoryMap, robot_position: (usize, usize)) -> Result<WideFactoryMap, MoveError> {
+    let first_move = (robot_position, factory_map.move_coords(robot_position, *direction).map_err(|_| MoveError::Impossible)?);
+    let mut move_requirements: MoveRequirements = HashMap::new();
+    let mut moves_to_calculate: Vec<Move> = vec![first_move];
+    while let Some((start_coords, end_coords)) = moves_to_calculate.pop() {
+        match calculate_move(factory_map, start_coords, end_coords) {
+            Ok(_) => { move_requirements.insert((start_coords, end_coords), Vec::new()); },
+            Err(MoveError::MoreMovesRequired(moves)) => {
+                move_requirements.insert((start_coords, end_coords), moves.clone());
+                let mut new_moves_to_calculate = moves.iter().filter(|&m| ! move_requirements.contains_key(m)).cloned().collect();
+                moves_to_calculate.append(&mut new_moves_to_calculate);
+            },
+            Err(e) => { return Err(e); },
+        }
+    }
+    let mut new_map = factory_map.clone();
+    let mut completed_moves: HashSet<Move> = HashSet::new();
+    while !move_requirements.is_empty() {
+        let (start_coords, end_coords) = move_requirements
+            .iter()
+            .filter_map(|(&mv, req)| {
+                if completed_moves.contains(&mv) || (!req.is_empty() && !req.iter().all(|r| completed_moves.contains(r))) {
+                    None
+                } else {
+                    Some(mv)
+                }
+            })
+            .next()
+            .ok_or(MoveError::Invalid("some required moves can't be made"))?;
+        let tile = new_map.get(start_coords).unwrap();
+        new_map.set(end_coords, tile).unwrap();
+        new_map.set(start_coords, WideFactoryTile::None).unwrap();
+        trace_move(tile == WideFactoryTile::Robot, start_coords, end_coords);
+        completed_moves.insert((start_coords, end_coords));
+        move_requirements.remove(&(start_coords, end_coords));
+    }
+    Ok(new_map)
+}
+
+fn calculate_move(factory_map: &WideFactoryMap, start_coords: (usize, usize), end_coords: (usize, usize)) -> Result<(), MoveError> {
+    let end_tile = factory_map.get(end_coords).map_err(|_| MoveError::Impossible)?;
+    match end_tile {
+        WideFactoryTile::None => {
+            Ok(())
+        },
+        WideFactoryTile::Robot => Err(MoveError::Invalid("more than one robot on the map")),
+        WideFactoryTile::Wall => Err(MoveError::Impossible),
+        WideFactoryTile::BoxLeft | WideFactoryTile::BoxRight => {
+            let box_this_half_start = end_coords;
+            let box_this_half_end = (
+                end_coords.0 + box_this_half_start.0 - start_coords.0,
+                end_coords.1 + box_this_half_start.1 - start_coords.1,
+            );
+            let other_half_direction = match end_tile {
+                WideFactoryTile::BoxLeft => Direction::East,
+                WideFactoryTile::BoxRight => Direction::West,
+                _ => unreachable!(),
+            };
+            let box_other_half_start = factory_map.move_coords(end_coords, other_half_direction)
+                .map_err(|_| MoveError::Invalid("box cut in half at the edge of the map"))?;
+            let box_other_half_end = (
+                end_coords.0 + box_other_half_start.0 - start_coords.0,
+                end_coords.1 + box_other_half_start.1 - start_coords.1,
+            );
+            if ! factory_map.contains_coords(box_this_half_end) {
+                Err(MoveError::Impossible)
+            } else {
+                let mut moves_required = Vec::new();
+                if box_this_half_end != box_other_half_start && box_other_half_end != box_this_half_start {
+                    moves_required.push((box_other_half_start, box_other_half_end));
+                }
+                moves_required.push((box_this_half_start, box_this_half_end));
+                Err(MoveError::MoreMovesRequired(moves_required))
+            }
+        }
+    }
+}
+
+fn calculate_total_gps_value_wide(factory_map: &WideFactoryMap) -> usize {
+    factory_map.iter_coords().filter_map(|(i, j)| match factory_map.get((i, j)).unwrap() { WideFactoryTile::BoxLeft => Some(100 * i + j), _ => None }).sum()
+}
+
+fn print_map(factory_map: &WideFactoryMap) {
+    if !progress::is_enabled(Verbosity::Verbose) { return; }
+    for row in factory_map.iter_rows() {
+        progress::debug(row.iter().map(|&t| t.to_char()).collect::<String>());
+    }
+}
+
+impl Solution for Day15 {
+    type Input = (FactoryMap, Vec<Direction>);
+
+    const PART_1_MESSAGE: &'static str = "Sum of coordinates";
+    const PART_2_MESSAGE: &'static str = "Sum of coordinates";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let [map_str, moves_str] = exact_sections(input)?;
+        let factory_map = str_to_grid(map_str, |c| match c {
+            '.' => Ok(FactoryTile::None),
+            '#' => Ok(FactoryTile::Wall),
+            'O' => Ok(FactoryTile::Box),
+            '@' => Ok(FactoryTile::Robot),
+            _ => Err(format!("invalid tile type: {c}")),
+        })?;
+        let robots: Vec<usize> = map_str.match_indices('@').map(|(i, _)| i).collect();
+        match robots[..] {
+            [_] => (),
+            [] => return Err(AocError::at(input, map_str, "no robot on the map")),
+            [_, second, ..] => return Err(AocError::at(input, &map_str[second..second + 1], "more than one robot on the map")),
+        }
+
+        let moves = moves_str.lines().flat_map(|l| l.char_indices().map(move |(i, c)| match c {
+            '^' => Ok(Direction::North),
+            '>' => Ok(Direction::East),
+            'v' => Ok(Direction::South),
+            '<' => Ok(Direction::West),
+            _ => Err(AocError::at(input, &l[i..i + c.len_utf8()], "invalid direction")),
+        })).collect::<Result<_, _>>()?;
+
+        Ok((factory_map, moves))
+    }
+
+    fn part1(&self, (factory_map, moves): &Self::Input) -> Result<Answer, AocError> {
+        let mut factory_map = factory_map.clone();
+        trace::record(|| Event::Grid { rows: trace::grid_rows(factory_map.iter_rows(), FactoryTile::to_char) });
+        moves.iter().try_for_each(|m| move_robot(m, &mut factory_map))?;
+        calculate_total_gps_value(&factory_map).try_into()
+    }
+
+    fn part2(&self, (factory_map, moves): &Self::Input) -> Result<Answer, AocError> {
+        let mut factory_map = widen_map(factory_map);
+        print_map(&factory_map);
+        trace::record(|| Event::Grid { rows: trace::grid_rows(factory_map.iter_rows(), WideFactoryTile::to_char) });
+        moves.iter().try_for_each(|m| move_robot_wide(m, &mut factory_map))?;
+        print_map(&factory_map);
+        calculate_total_gps_value_wide(&factory_map).try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_15.txt");
+    const EXAMPLE_SMALL: &str = include_str!("examples/day_15_small.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day15.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(10092)));
+    }
+
+    #[test]
+    fn part_1_example_small() {
+        assert_eq!(Day15.parse_and_solve(EXAMPLE_SMALL, Part::One), Ok(Answer::Number(2028)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day15.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(9021)));
+    }
+
+    #[test]
+    fn robot_count() {
+        let no_robot = Day15.parse("#####\n#.O.#\n#####\n\n<>");
+        assert_eq!(no_robot.unwrap_err().to_string(), "no robot on the map (line 1, column 1)");
+        let two_robots = Day15.parse("#####\n#@O@#\n#####\n\n<>");
+        assert_eq!(two_robots.unwrap_err().to_string(), "more than one robot on the map (line 2, column 4)");
+    }
+
+    // without walls around it, the robot simply can't leave the map
+    #[test]
+    fn open_map() {
+        assert_eq!(Day15.parse_and_solve("@O.\n\n<^>>>>v", Part::One), Ok(Answer::Number(2)));
+        assert_eq!(Day15.parse_and_solve("@O.\n\n<^>>>>v", Part::Two), Ok(Answer::Number(4)));
+    }
+}
@@ -0,0 +1,207 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use strum::IntoEnumIterator;
+use crate::cancel::{self, CancellationToken};
+use crate::coord_grid::{Direction, Grid, Path, Pose, str_to_grid};
+use crate::error::AocError;
+use crate::params::{parse_value, unknown_param, Param};
+use crate::progress::{self, Verbosity};
+use crate::solution::{Answer, Part, Solution, Variant};
+
+pub struct Day16 {
+    pub move_score: Score,
+    pub turn_score: Score,
+}
+
+impl Default for Day16 {
+    fn default() -> Self {
+        Self { move_score: 1, turn_score: 1000 }
+    }
+}
+
+type Score = u64;
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum MazeTile {
+    Start,
+    End,
+    Wall,
+    #[default]
+    None,
+}
+
+type Maze = Grid<MazeTile>;
+type MazeScores = HashMap<Pose, Score>;
+
+impl MazeTile {
+    fn to_char(self) -> char {
+        match self {
+            MazeTile::Start => 'S',
+            MazeTile::End => 'E',
+            MazeTile::Wall => '#',
+            MazeTile::None => '.',
+        }
+    }
+}
+
+impl Day16 {
+    fn find_lowest_score(&self, maze: &Maze) -> Result<Score, AocError> {
+        // note: this version takes ~2min due to slow recursive solution!
+        let start_position: (usize, usize) = maze.position(MazeTile::Start).ok_or("unable to find start")?;
+        let end_position: (usize, usize) = maze.position(MazeTile::End).ok_or("unable to find end")?;
+        let mut scores = MazeScores::new();
+        let token = cancel::current();
+        self.recurse(start_position, Direction::East, 0, &mut scores, maze, &token);
+        token.check()?;
+        Ok(*Direction::iter().filter_map(|d| scores.get(&(end_position, d))).min().ok_or("unable to calculate lowest score")?)
+    }
+
+    // also returns one of the paths with the lowest score
+    fn dijkstra(&self, maze: &Maze) -> Option<(Score, Path)> {
+        let start_position = maze.position(MazeTile::Start)?;
+        let mut scores = MazeScores::new();
+        let mut previous: HashMap<Pose, Pose> = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((0, start_position, Direction::East, None))]);
+        while let Some(Reverse((score, position, direction, from))) = queue.pop() {
+            if scores.get(&(position, direction)).is_some_and(|&s| s <= score) { continue; }
+            scores.insert((position, direction), score);
+            if let Some(from) = from { previous.insert((position, direction), from); }
+            match maze.get(position).ok()? {
+                MazeTile::End => {
+                    let mut poses = vec![(position, direction)];
+                    while let Some(&pose) = previous.get(poses.last().unwrap()) { poses.push(pose); }
+                    poses.reverse();
+                    return Some((score, Path::new(poses)));
+                }
+                MazeTile::Wall => continue,
+                MazeTile::Start | MazeTile::None => (),
+            }
+            for (ang, turn_score) in [(0, 0), (90, self.turn_score), (180, 2 * self.turn_score), (270, self.turn_score)] {
+                let new_direction = direction.turn_anticlockwise(ang).unwrap();
+                if let Ok(new_position) = maze.move_coords(position, new_direction) {
+                    queue.push(Reverse((score + turn_score + self.move_score, new_position, new_direction, Some((position, direction)))));
+                }
+            }
+        }
+        None
+    }
+
+    fn recurse(&self, position: (usize, usize), direction: Direction, current_score: Score, scores: &mut MazeScores, maze: &Maze, token: &CancellationToken) {
+        if token.is_cancelled() { return; }
+        if let Some(&previous_score) = scores.get(&(position, direction)) {
+            if previous_score <= current_score {
+                return;
+            }
+        }
+        scores.insert((position, direction), current_score);
+        match maze.get(position).expect("invalid coordinates") {
+            MazeTile::Wall | MazeTile::End => (),
+            MazeTile::Start | MazeTile::None => {
+                [(0, 0), (90, self.turn_score), (180, 2 * self.turn_score), (270, self.turn_score)].iter().for_each(|(ang, turn_score)| {
+                    let new_direction = direction.turn_anticlockwise(*ang).unwrap();
+                    if let Ok(new_position) = maze.move_coords(position, new_direction) {
+                        self.recurse(
+                            new_position,
+                            new_direction,
+                            current_score + turn_score + self.move_score,
+                            scores,
+                            maze,
+                            token,
+                        );
+                    }
+                });
+            }
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    const PART_1_MESSAGE: &'static str = "Lowest possible score";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        str_to_grid(input, |c| match c {
+            'S' => Ok(MazeTile::Start),
+            'E' => Ok(MazeTile::End),
+            '#' => Ok(MazeTile::Wall),
+            '.' => Ok(MazeTile::None),
+            _ => Err(format!("invalid character: {c}")),
+        })
+    }
+
+    fn part1(&self, maze: &Self::Input) -> Result<Answer, AocError> {
+        Ok(self.find_lowest_score(maze)?.into())
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![Variant {
+            part: Part::One,
+            name: "dijkstra",
+            solve: |day, maze| {
+                let (score, path) = day.dijkstra(maze).ok_or("no path to the end")?;
+                if progress::is_enabled(Verbosity::Verbose) {
+                    path.render(maze, MazeTile::to_char).into_iter().for_each(progress::debug);
+                }
+                Ok(score.into())
+            },
+        }]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("move_score", self.move_score, "score for moving forward one tile"),
+            Param::new("turn_score", self.turn_score, "score for turning by 90 degrees"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), AocError> {
+        match name {
+            "move_score" => self.move_score = parse_value(name, value)?,
+            "turn_score" => self.turn_score = parse_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::DynSolution;
+
+    const EXAMPLE: &str = include_str!("examples/day_16.txt");
+    const EXAMPLE_SECOND: &str = include_str!("examples/day_16_second.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day16::default().parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(7036)));
+    }
+
+    #[test]
+    fn part_1_example_second() {
+        assert_eq!(Day16::default().parse_and_solve(EXAMPLE_SECOND, Part::One), Ok(Answer::Number(11048)));
+    }
+
+    #[test]
+    fn part_1_dijkstra() {
+        let day = Day16::default();
+        for (example, expected) in [(EXAMPLE, 7036), (EXAMPLE_SECOND, 11048)] {
+            let maze = day.parse_input(example).unwrap();
+            assert_eq!(day.solve_variant(maze.as_ref(), Part::One, "dijkstra"), Ok(Answer::Number(expected)));
+        }
+    }
+
+    #[test]
+    fn lowest_score_path() {
+        let day = Day16::default();
+        let maze = day.parse(EXAMPLE).unwrap();
+        let (score, path) = day.dijkstra(&maze).unwrap();
+        assert_eq!(path.cost(day.move_score, day.turn_score), score);
+        assert_eq!((path.len(), path.turns()), (36, 7));
+        assert!(!path.is_self_intersecting());
+        assert_eq!(path.poses().first(), Some(&(maze.position(MazeTile::Start).unwrap(), Direction::East)));
+        assert_eq!(path.poses().last().map(|p| p.0), maze.position(MazeTile::End));
+        assert!(path.cells().iter().all(|&c| maze.get(c) != Ok(MazeTile::Wall)));
+    }
+}
@@ -0,0 +1,250 @@
+use itertools::join;
+use crate::{cancel, progress};
+use crate::error::AocError;
+use crate::params::{parse_value, unknown_param, Param};
+use crate::parsing::{comma_list, exact_sections, parse_at, strip_prefix_at};
+use crate::solution::{Answer, Part, Solution, Variant};
+
+pub mod brute_force;
+mod fragments;
+
+pub struct Day17 {
+    // values of register A tried by the brute-force variant of part 2 before giving up
+    pub brute_force_limit: u64,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Self { brute_force_limit: 100_000_000 }
+    }
+}
+
+type ProgNum = u8;
+type RegNum = u64;
+type OutNum = RegNum;
+type Program = Vec<ProgNum>;
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    a: RegNum,
+    b: RegNum,
+    c: RegNum,
+    inst_ptr: usize,
+}
+
+impl Computer {
+    fn literal_operand(&self, operand: ProgNum) -> ProgNum {
+        operand
+    }
+
+    fn combo_operand(&self, o: ProgNum) -> Result<RegNum, AocError> {
+        match o {
+            0 => Ok(0),
+            1 => Ok(1),
+            2 => Ok(2),
+            3 => Ok(3),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(AocError::new(format!("invalid combo operand {o} at position {}", self.inst_ptr + 1))),
+        }
+    }
+
+    // A divided by 2 to the power of the combo operand, which is 0 once the divisor doesn't fit in a register
+    fn divide_a(&self, operand: ProgNum) -> Result<RegNum, AocError> {
+        let exponent = u32::try_from(self.combo_operand(operand)?).unwrap_or(u32::MAX);
+        Ok(self.a.checked_shr(exponent).unwrap_or(0))
+    }
+
+    fn adv(&mut self, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        self.a = self.divide_a(operand)?;
+        Ok(None)
+    }
+
+    fn bxl(&mut self, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        let o: RegNum = self.literal_operand(operand).into();
+        self.b ^= o;
+        Ok(None)
+    }
+
+    fn bst(&mut self, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        self.b = self.combo_operand(operand)? % 8;
+        Ok(None)
+    }
+
+    fn jnz(&mut self, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        if self.a != 0 {
+            progress::debug(format_args!("Jumping to {operand} - A register contains {}", self.a));
+            self.inst_ptr = self.literal_operand(operand).into();
+        } else {
+            progress::debug("A register contains 0 - not jumping");
+            self.inst_ptr += 2;
+        }
+        Ok(None)
+    }
+
+    fn bxc(&mut self, _operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        self.b ^= self.c;
+        Ok(None)
+    }
+
+    fn out(&mut self, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        Ok(Some(self.combo_operand(operand)? % 8))
+    }
+
+    fn bdv(&mut self, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        self.b = self.divide_a(operand)?;
+        Ok(None)
+    }
+
+    fn cdv(&mut self, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        self.c = self.divide_a(operand)?;
+        Ok(None)
+    }
+
+    fn execute_instruction(&mut self, opcode: ProgNum, operand: ProgNum) -> Result<Option<OutNum>, AocError> {
+        match opcode {
+            0 => self.adv(operand),
+            1 => self.bxl(operand),
+            2 => self.bst(operand),
+            3 => self.jnz(operand),
+            4 => self.bxc(operand),
+            5 => self.out(operand),
+            6 => self.bdv(operand),
+            7 => self.cdv(operand),
+            _ => Err(AocError::new(format!("invalid opcode {opcode} at position {}", self.inst_ptr))),
+        }
+    }
+
+    fn run_program(&mut self, program: &[ProgNum]) -> Result<Vec<OutNum>, AocError> {
+        progress::debug(format_args!("Running program: {program:?}"));
+        progress::debug(format_args!("on computer: {self:?}"));
+        // a jump can loop forever, so the program has to stop when the solution is cancelled
+        let token = cancel::current();
+        let mut output = Vec::new();
+        // (reading either the opcode or the operand past the end of the program halts it)
+        while let (Some(&opcode), Some(&operand)) = (program.get(self.inst_ptr), program.get(self.inst_ptr + 1)) {
+            token.check()?;
+            if let Some(o) = self.execute_instruction(opcode, operand)? {
+                output.push(o);
+            }
+            if opcode != 3 { self.inst_ptr += 2; }
+        }
+        Ok(output)
+    }
+}
+
+// opcodes whose operand is a combo operand, for which 7 is reserved
+const COMBO_OPCODES: [ProgNum; 5] = [0, 2, 5, 6, 7];
+
+fn parse_program(input: &str, program: &str) -> Result<Program, AocError> {
+    let prog = comma_list::<ProgNum>(input, program)?;
+    if prog.len() % 2 != 0 {
+        return Err(AocError::at(input, program, "expected pairs of opcodes and operands"));
+    }
+    for (i, (&value, span)) in prog.iter().zip(program.split(',').map(str::trim)).enumerate() {
+        if value > 7 {
+            return Err(AocError::at(input, span, "expected a 3-bit number"));
+        }
+        if i % 2 == 1 && value == 7 && COMBO_OPCODES.contains(&prog[i - 1]) {
+            return Err(AocError::at(input, span, "invalid combo operand"));
+        }
+    }
+    Ok(prog)
+}
+
+impl Solution for Day17 {
+    type Input = (Computer, Program);
+
+    const PART_1_MESSAGE: &'static str = "Output";
+    const PART_2_MESSAGE: &'static str = "Lowest value for register A";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let [registers, program] = exact_sections(input)?;
+        let registers_vec: Vec<&str> = registers.lines().collect();
+        let [a, b, c] = registers_vec[..] else { return Err(AocError::at(input, registers, "expected three registers")); };
+        let a = parse_at::<RegNum>(input, strip_prefix_at(input, a, "Register A: ")?)?;
+        let b = parse_at::<RegNum>(input, strip_prefix_at(input, b, "Register B: ")?)?;
+        let c = parse_at::<RegNum>(input, strip_prefix_at(input, c, "Register C: ")?)?;
+        let prog = parse_program(input, strip_prefix_at(input, program, "Program: ")?)?;
+
+        Ok((
+            Computer { a, b, c, inst_ptr: 0 },
+            prog
+        ))
+    }
+
+    fn part1(&self, (computer, program): &Self::Input) -> Result<Answer, AocError> {
+        let out_vec = computer.clone().run_program(program)?;
+        Ok(join(out_vec, ",").into())
+    }
+
+    fn part2(&self, (_, program): &Self::Input) -> Result<Answer, AocError> {
+        let library = fragments::build_fragment_library();
+        progress::debug(format_args!("Fragment library constructed: {:?} items", library.iter().map(|l| l.len()).collect::<Vec<usize>>()));
+        let a = fragments::assemble_fragments(&library, program)?;
+        Ok(u64::try_from(a).map_err(|_| "register value too large")?.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new("brute_force_limit", self.brute_force_limit, "values of register A tried by the brute_force variant")]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), AocError> {
+        match name {
+            "brute_force_limit" => self.brute_force_limit = parse_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![Variant {
+            part: Part::Two,
+            name: "brute_force",
+            solve: |day, (_, program)| {
+                let a = brute_force::find_register_a(program, day.brute_force_limit.into())?;
+                Ok(u64::try_from(a).map_err(|_| "register value too large")?.into())
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::DynSolution;
+
+    const EXAMPLE: &str = include_str!("examples/day_17.txt");
+    // (part 2 is specific to the program from the puzzle input, so can't be checked against the example)
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day17::default().parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Text(String::from("4,6,3,5,6,3,5,2,1,0"))));
+    }
+
+    #[test]
+    fn invalid_programs() {
+        let registers = "Register A: 729\nRegister B: 0\nRegister C: 0\n\n";
+        for (program, message) in [
+            ("Program: 0,1,5", "expected pairs of opcodes and operands (line 5, column 10)"),
+            ("Program: 0,1,8,4", "expected a 3-bit number (line 5, column 14)"),
+            ("Program: 0,1,5,7", "invalid combo operand (line 5, column 16)"),
+        ] {
+            let error = Day17::default().parse(&format!("{registers}{program}")).unwrap_err();
+            assert_eq!(error.to_string(), message);
+        }
+        // bxl takes a literal operand, so 7 is allowed there
+        assert!(Day17::default().parse(&format!("{registers}Program: 1,7")).is_ok());
+    }
+
+    #[test]
+    fn jump_into_operand() {
+        // jumping to position 1 runs the operand 7 of bxl as cdv, with the invalid combo operand 7
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,7,1,3,1";
+        assert!(Day17::default().parse_and_solve(input, Part::One).is_err());
+        // a jump to the last value halts, since there is no operand to read
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,3,3";
+        assert_eq!(Day17::default().parse_and_solve(input, Part::One), Ok(Answer::Text(String::from("1"))));
+    }
+}
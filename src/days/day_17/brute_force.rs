@@ -0,0 +1,116 @@
+// Brute-force attempt at part 2, kept as a variant for reference - see `fragments` for the working solution.
+// Note that like the working solution, this is specific to the program from the puzzle input.
+
+use crate::cancel;
+use crate::error::AocError;
+use crate::progress;
+use super::ProgNum;
+
+type RegNum = u128;
+type OutNum = ProgNum;
+const EXP_BASE: RegNum = 2;
+
+#[derive(Debug)]
+struct Computer {
+    a: RegNum,
+    b: RegNum,
+    c: RegNum,
+    inst_ptr: usize,
+}
+
+impl Computer {
+    fn execute_hardcoded_instruction(&mut self) -> Option<OutNum> {
+        let out = self.inst_ptr == 10;
+        match self.inst_ptr {
+            0 => { self.b = self.a % 8 },
+            2 => { self.b ^= 1 },  // 1 = 0b...00001
+            4 => { self.c = self.a / EXP_BASE.pow(self.b as u32) },
+            6 => { self.b ^= 5 },  // 5 = 0b...00101
+            8 => { self.b ^= self.c },
+            10 => (),
+            12 => { self.a /= 8 },
+            14 => (),
+            _ => panic!("invalid value for self.inst_ptr"),
+        };
+        self.inst_ptr = if self.inst_ptr == 14 && self.a != 0 { 0 } else { self.inst_ptr + 2 };
+        if out { Some((self.b % 8) as u8) } else { None }
+    }
+
+    fn run_program(&mut self, program: &[ProgNum]) -> Vec<OutNum> {
+        let mut output = Vec::new();
+        while self.inst_ptr < program.len() {
+            if let Some(o) = self.execute_hardcoded_instruction() {
+                output.push(o);
+            }
+        }
+        output
+    }
+}
+
+// Each run of program:
+// 1. Set B to A % 8
+// 2. Set B to B XOR 0b...00001
+// 3. Set C to A / 2^B
+// 4. Set B to B XOR 0b...00101
+// 5. Set B to B XOR C
+// 6. Output B % 8
+// 7. Set A to A // 8
+// 8. Jump to start if A != 0
+//
+// 1-2. Set B to (A % 8) XOR 0b...00001
+// 3. Set C to A / 2^B
+// 4-5. Set B to (B XOR 0b...00101) XOR C
+// 6. Output B % 8
+// 7. Set A to A // 8
+//
+// 1-3. Set (B, C) to ((A%8)x1, A/2^((A%8)x1))
+// 4-5. Set B to (B XOR 0b...00101) XOR C
+// 6. Output B % 8
+// 7. Set A to A // 8
+//
+// 1-5. Set (B, C) to ((((A%8)x1)x5)x(A/2^((A%8)x1)), A/2^((A%8)x1))
+// 6. Output B % 8
+// 7. Set A to A // 8
+
+fn start_computer(a: RegNum) -> Computer {
+    Computer { a, b: 0, c: 0, inst_ptr: 0 }
+}
+
+fn iter_a_only(a: RegNum) -> (RegNum, OutNum) {
+    let rsh = (a & 0b111) as u8 ^ 0b001;
+    let out = (((a + 4) & 0b111) as u8) ^ (((a >> rsh) & 0b111) as u8);
+    let new_a = a / 8;
+    (new_a, out)
+}
+
+fn test_program_a_only_backtracking(a: RegNum, program: &[ProgNum]) -> bool {
+    // (validated successfully using part 1 input)
+    let mut a = a;
+    let mut i = 0;
+    while a > 0 {
+        if i >= program.len() { return false; }
+        let (new_a, out) = iter_a_only(a);
+        if out != program[i] { return false; }
+        a = new_a;
+        i += 1;
+    }
+    i == program.len()
+}
+
+pub fn find_register_a(program: &[ProgNum], limit: RegNum) -> Result<RegNum, AocError> {
+    progress::debug(format_args!("Program from input produces {:?}", start_computer(64854237).run_program(program)));
+    let token = cancel::current();
+    let mut a = 0;
+    while ! test_program_a_only_backtracking(a, program) {
+        if a % 1_000_000 == 0 {
+            token.check()?;
+            token.set_progress(format_args!("tested up to a = {a:e}"));
+        }
+        if a % 100_000_000 == 0 { progress::info(format_args!("Tested up to a = {a:e}")); }
+        a += 1;
+        if a >= limit { return Err(AocError::new(format!("no value found below {limit}"))); }
+    }
+    Ok(a)
+    // No result for values up to 2e9!
+    // Brute-force solution not viable -> see `fragments` for working solution
+}
@@ -1,15 +1,10 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use crate::cancel;
+use crate::error::AocError;
+use crate::progress;
+use super::ProgNum;
 
-const OUTPUT_MESSAGE: &str = "Output";
-const INPUT_FILE: &str = "input/day_17.txt";
-
-type ProgNum = u8;
 type RegNum = u128;
 type OutNum = ProgNum;
-const PROG_LEN: usize = 16;
-type Program = [ProgNum; PROG_LEN];
-const PROGRAM: Program = [2,4,1,1,7,5,1,5,4,0,5,5,0,3,3,0];
 type FragmentLibrary = [Vec<RegNum>; 8];
 
 // The specified program can be expressed purely in terms of register A's value:
@@ -44,9 +39,9 @@ fn get_fragment_output(f: RegNum) -> OutNum {
     (((f + 4) & 0b111) as u8) ^ (((f >> rsh) & 0b111) as u8)
 }
 
-fn build_fragment_library() -> FragmentLibrary {
+pub fn build_fragment_library() -> FragmentLibrary {
     let mut library: FragmentLibrary = (0..8).map(|_| Vec::new()).collect::<Vec<Vec<RegNum>>>().try_into().unwrap();
-    (0..=0b11_1111_1111).for_each(|f| library[get_fragment_output(f) as usize].push(f.clone()));
+    (0..=0b11_1111_1111).for_each(|f| library[get_fragment_output(f) as usize].push(f));
     library
 }
 
@@ -55,16 +50,17 @@ fn do_fragments_overlap(l: RegNum, r: RegNum) -> bool {
     (l & 0b111_1111) == (r >> 3)
 }
 
-fn do_all_fragments_overlap(fragments: [RegNum; PROG_LEN]) -> bool {
+fn do_all_fragments_overlap(fragments: &[RegNum]) -> bool {
     (1..fragments.len()).all(|i| do_fragments_overlap(fragments[i - 1], fragments[i]))
 }
 
-fn combine_overlapping_fragments(fragments: [RegNum; PROG_LEN]) -> RegNum {
+fn combine_overlapping_fragments(fragments: &[RegNum]) -> RegNum {
     if ! do_all_fragments_overlap(fragments) { panic!("fragments do not overlap"); }
-    fragments.iter().cloned().reduce(|acc, f| (acc << 3) + (f & 0b111)).expect("unable to combine fragments").clone()
+    fragments.iter().cloned().reduce(|acc, f| (acc << 3) + (f & 0b111)).expect("unable to combine fragments")
 }
 
-fn assemble_fragments(library: &FragmentLibrary, program: &Program) -> RegNum {
+pub fn assemble_fragments(library: &FragmentLibrary, program: &[ProgNum]) -> Result<RegNum, AocError> {
+    let prog_len = program.len();
     // (note .rev()! A is deconstructed from the right, therefore the fragments need to be reversed w.r.t. the program)
     let mut possible_fragments_per_position: Vec<Vec<RegNum>> = program.iter().map(|&n| library[n as usize].clone()).rev().collect();
 
@@ -74,60 +70,55 @@ fn assemble_fragments(library: &FragmentLibrary, program: &Program) -> RegNum {
         // "Basic filtering": at each position, find all fragments which overlap with *any* fragment at the next/previous position
         // Each iteration reduces the number of possible fragments further without explicitly needing to compare distant positions
 
-        for ii in 1..PROG_LEN {
-            let i = PROG_LEN - ii;
-            possible_fragments_per_position[i] = possible_fragments_per_position[i].iter().filter(|&f| {
-                possible_fragments_per_position[i - 1].iter().any(|&f2| do_fragments_overlap(f2, *f))
-                    && (i > 4 || (*f >> 3 * (i + 1)) == 0)
-            }).cloned().collect()
+        for ii in 1..prog_len {
+            let i = prog_len - ii;
+            let (previous, current) = possible_fragments_per_position.split_at_mut(i);
+            current[0].retain(|&f| {
+                previous[i - 1].iter().any(|&f2| do_fragments_overlap(f2, f))
+                    && (i > 4 || (f >> (3 * (i + 1))) == 0)
+            })
         }
 
-        for i in 0..(PROG_LEN - 1) {
-            possible_fragments_per_position[i] = possible_fragments_per_position[i].iter().filter(|&f| {
-                possible_fragments_per_position[i + 1].iter().any(|&f2| do_fragments_overlap(*f, f2))
-            }).cloned().collect()
+        for i in 0..(prog_len - 1) {
+            let (current, next) = possible_fragments_per_position.split_at_mut(i + 1);
+            current[i].retain(|&f| {
+                next[0].iter().any(|&f2| do_fragments_overlap(f, f2))
+            })
         }
 
-        println!("Possible fragments per position after {t} filtering steps: {:?}", possible_fragments_per_position.iter().map(|v| v.len()).collect::<Vec<usize>>());
-        println!("Total nr. of possibilities: {:.3e}", possible_fragments_per_position.iter().map(|v| v.len() as u128).product::<u128>());
+        progress::debug(format_args!("Possible fragments per position after {t} filtering steps: {:?}", possible_fragments_per_position.iter().map(|v| v.len()).collect::<Vec<usize>>()));
+        progress::debug(format_args!("Total nr. of possibilities: {:.3e}", possible_fragments_per_position.iter().map(|v| v.len() as u128).product::<u128>()));
     }
 
-    let mut fragments: [RegNum; PROG_LEN];
-    let mut frag_idxs: [usize; PROG_LEN] = [0; PROG_LEN];
-    let max_frag_idxs: [usize; PROG_LEN] = possible_fragments_per_position.iter().map(|v| v.len()).collect::<Vec<usize>>().try_into().unwrap();
+    let mut fragments: Vec<RegNum>;
+    let mut frag_idxs: Vec<usize> = vec![0; prog_len];
+    let max_frag_idxs: Vec<usize> = possible_fragments_per_position.iter().map(|v| v.len()).collect();
+    if max_frag_idxs.contains(&0) { return Err(AocError::new("no combination of fragments produces the program")); }
+    let token = cancel::current();
     let mut t: u128 = 0;
     loop {
         t += 1;
-        if t % 10_000_000 == 0 { println!("starting {t:e}th iteration"); }
+        if t.is_multiple_of(1_000_000) {
+            token.check()?;
+            token.set_progress(format_args!("{t:e} combinations of fragments tried"));
+        }
+        if t.is_multiple_of(10_000_000) { progress::debug(format_args!("starting {t:e}th iteration")); }
 
         // get fragments by index
-        fragments = frag_idxs.iter().enumerate().map(|(i, &idx)| possible_fragments_per_position[i][idx]).collect::<Vec<RegNum>>().try_into().unwrap();
+        fragments = frag_idxs.iter().enumerate().map(|(i, &idx)| possible_fragments_per_position[i][idx]).collect();
 
         // check for overlap
-        if do_all_fragments_overlap(fragments) { break; }
+        if do_all_fragments_overlap(&fragments) { break; }
 
         // increment indices
         let mut i = frag_idxs.len() - 1;
         frag_idxs[i] += 1;
         while frag_idxs[i] >= max_frag_idxs[i] {
+            if i == 0 { return Err(AocError::new("no combination of fragments produces the program")); }
             frag_idxs[i] = 0;
             i -= 1;
             frag_idxs[i] += 1;
         }
     }
-    combine_overlapping_fragments(fragments)
-}
-
-fn calculate_result(_lines: Lines<BufReader<File>>) -> Result<RegNum, ()> {
-    let library = build_fragment_library();
-    println!("Fragment library constructed: {:?} items", library.iter().map(|l| l.len()).collect::<Vec<usize>>());
-    let a = assemble_fragments(&library, &PROGRAM);
-    Ok(a)
-}
-
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+    Ok(combine_overlapping_fragments(&fragments))
 }
@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::coord_grid::{Direction, Grid, Path};
+use crate::{cancel, progress};
+use crate::error::AocError;
+use crate::params::{parse_value, unknown_param, Param};
+use crate::parsing::comma_list;
+use crate::solution::{Answer, Part, Solution, Variant};
+
+pub struct Day18 {
+    pub grid_size: usize,
+    pub n_bytes: usize,
+    pub start: (usize, usize),
+    // defaults to the corner opposite the start when not set
+    pub end: Option<(usize, usize)>,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Self { grid_size: 71, n_bytes: 1024, start: (0, 0), end: None }
+    }
+}
+
+type PathLength = u32;
+
+type MemorySpace = Grid<bool>;  // true if accessible, false if corrupted
+type ShortestPaths = Grid<PathLength>;
+type LastCoords = Grid<Option<(usize, usize)>>;
+// the start and the exit
+type Endpoints = ((usize, usize), (usize, usize));
+
+fn parse_coords(name: &str, value: &str) -> Result<(usize, usize), AocError> {
+    let (x, y) = value.split_once(',').ok_or_else(|| AocError::new(format!("expected x,y for parameter {name}, got {value}")))?;
+    Ok((parse_value(name, x.trim())?, parse_value(name, y.trim())?))
+}
+
+fn dijkstra(grid: &MemorySpace, start_coords: (usize, usize), end_coords: (usize, usize)) -> Option<Path> {
+    let mut shortest_paths = ShortestPaths::create(grid.width(), grid.height());
+    shortest_paths.set_all(PathLength::MAX);
+    shortest_paths.set(start_coords, 0).unwrap();
+    let mut last_coords = LastCoords::create(grid.width(), grid.height());
+    let mut unchecked_coords: HashSet<(usize, usize)> = grid.iter_coords().filter(|&c| grid.get(c).unwrap()).collect();
+
+    while !unchecked_coords.is_empty() {
+        let closest = *unchecked_coords.iter().sorted_by_key(|&c| shortest_paths.get(*c).unwrap()).next().unwrap();
+        if shortest_paths.get(closest).unwrap() == PathLength::MAX { break; }
+        if closest == end_coords {
+            let mut coords = vec![end_coords];
+            while let Some(c) = last_coords.get(*coords.last().unwrap()).unwrap() { coords.push(c); }
+            coords.reverse();
+            return Path::from_coords(&coords, Direction::East);
+        }
+        unchecked_coords.remove(&closest);
+        Direction::main_directions().iter().for_each(|d| {
+            if let Ok(neighbour) = grid.move_coords(closest, *d) {
+                let new_dist = shortest_paths.get(closest).unwrap() + 1;
+                if new_dist < shortest_paths.get(neighbour).unwrap() {
+                    shortest_paths.set(neighbour, new_dist).unwrap();
+                    last_coords.set(neighbour, Some(closest)).unwrap();
+                }
+            }
+        });
+    }
+
+    None
+}
+
+impl Day18 {
+    fn end_coords(&self) -> (usize, usize) {
+        self.end.unwrap_or((self.grid_size.saturating_sub(1), self.grid_size.saturating_sub(1)))
+    }
+
+    // (both have to lie in the memory space)
+    fn endpoints(&self) -> Result<Endpoints, AocError> {
+        for (name, (x, y)) in [("start", self.start), ("end", self.end_coords())] {
+            if x >= self.grid_size || y >= self.grid_size {
+                return Err(AocError::new(format!("{name} {x},{y} lies outside the {0}x{0} memory space", self.grid_size)));
+            }
+        }
+        Ok((self.start, self.end_coords()))
+    }
+
+    fn dijkstra_path(&self, grid: &MemorySpace) -> Result<Option<Path>, AocError> {
+        let (start, end) = self.endpoints()?;
+        Ok(dijkstra(grid, start, end))
+    }
+
+    fn path_len(&self, grid: &MemorySpace) -> Result<Option<usize>, AocError> {
+        let (start, end) = self.endpoints()?;
+        Ok(grid.bfs_distances(&[start], |accessible| accessible).get(end).unwrap_or(None))
+    }
+
+    fn corrupted_memory_space(&self, byte_coords: &[(usize, usize)]) -> MemorySpace {
+        let mut mem = MemorySpace::create(self.grid_size, self.grid_size);
+        mem.set_all(true);
+        byte_coords.iter().for_each(|&c| { let _ = mem.set(c, false); });
+        mem
+    }
+
+    fn test_fails_at_n(&self, n: usize, byte_coords: &[(usize, usize)]) -> Result<bool, AocError> {
+        progress::debug(format_args!("Testing with {n} bytes..."));
+        let mem = self.corrupted_memory_space(&byte_coords[..=n]);
+        Ok(self.path_len(&mem)?.is_none())
+    }
+}
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+
+    const PART_1_MESSAGE: &'static str = "Shortest path length";
+    const PART_2_MESSAGE: &'static str = "First byte causing failure";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        input.lines().map(|l| {
+            match comma_list(input, l)?[..] {
+                [x, y] => Ok((x, y)),
+                _ => Err(AocError::at(input, l, "invalid coordinates")),
+            }
+        }).collect()
+    }
+
+    fn part1(&self, byte_coords: &Self::Input) -> Result<Answer, AocError> {
+        let mem = self.corrupted_memory_space(&byte_coords[..self.n_bytes.min(byte_coords.len())]);
+        self.path_len(&mem)?.ok_or("no path to the exit")?.try_into()
+    }
+
+    fn part2(&self, byte_coords: &Self::Input) -> Result<Answer, AocError> {
+        progress::debug(format_args!("Byte positions: {byte_coords:?} ({} total)", byte_coords.len()));
+        let token = cancel::current();
+        if byte_coords.is_empty() || !self.test_fails_at_n(byte_coords.len() - 1, byte_coords)? {
+            return Err(AocError::new("the path is never blocked"));
+        }
+        // the path is blocked after byte n_max falls, and open before byte n_min falls
+        let (mut n_min, mut n_max) = (0, byte_coords.len() - 1);
+        while n_min < n_max {
+            token.check()?;
+            token.set_progress(format_args!("first blocking byte between {n_min} and {n_max}"));
+            let n = (n_min + n_max) / 2;
+            if self.test_fails_at_n(n, byte_coords)? { n_max = n; } else { n_min = n + 1; }
+        }
+        let coords_fail = byte_coords[n_max];
+        Ok(format!("{},{}", coords_fail.0, coords_fail.1).into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("grid_size", self.grid_size, "width and height of the memory space"),
+            Param::new("n_bytes", self.n_bytes, "number of fallen bytes in part 1"),
+            Param::new("start", format!("{},{}", self.start.0, self.start.1), "coordinates of the start, as x,y"),
+            Param::new("end", format!("{},{}", self.end_coords().0, self.end_coords().1), "coordinates of the exit, as x,y"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), AocError> {
+        match name {
+            "grid_size" => self.grid_size = parse_value(name, value)?,
+            "n_bytes" => self.n_bytes = parse_value(name, value)?,
+            "start" => self.start = parse_coords(name, value)?,
+            "end" => self.end = Some(parse_coords(name, value)?),
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![Variant {
+            part: Part::One,
+            name: "dijkstra",
+            solve: |day, byte_coords| {
+                let mem = day.corrupted_memory_space(&byte_coords[..day.n_bytes.min(byte_coords.len())]);
+                day.dijkstra_path(&mem)?.ok_or("no path to the exit")?.len().try_into()
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::DynSolution;
+
+    const EXAMPLE: &str = include_str!("examples/day_18.txt");
+
+    fn example_day() -> Day18 {
+        Day18 { grid_size: 7, n_bytes: 12, ..Day18::default() }
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(example_day().parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(22)));
+    }
+
+    #[test]
+    fn part_1_dijkstra_path() {
+        let day = example_day();
+        let bytes = day.parse(EXAMPLE).unwrap();
+        let mem = day.corrupted_memory_space(&bytes[..day.n_bytes]);
+        let path = day.dijkstra_path(&mem).unwrap().unwrap();
+        assert_eq!(path.len(), 22);
+        assert!(!path.is_self_intersecting());
+        assert!(path.cells().iter().all(|&c| mem.get(c) == Ok(true)));
+    }
+
+    #[test]
+    fn endpoints_outside_grid() {
+        let day = Day18 { end: Some((7, 3)), ..example_day() };
+        assert_eq!(day.parse_and_solve(EXAMPLE, Part::One), Err(AocError::new("end 7,3 lies outside the 7x7 memory space")));
+        assert!(day.solve_variant(day.parse_input(EXAMPLE).unwrap().as_ref(), Part::One, "dijkstra").is_err());
+        let day = Day18 { grid_size: 0, ..Day18::default() };
+        assert_eq!(day.parse_and_solve(EXAMPLE, Part::Two), Err(AocError::new("start 0,0 lies outside the 0x0 memory space")));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(example_day().parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Text(String::from("6,1"))));
+    }
+
+    #[test]
+    fn part_2_independent_of_n_bytes() {
+        // the path is already blocked after 22 bytes, and there are only 25 bytes in the example
+        for n_bytes in [0, 22, 30] {
+            let day = Day18 { n_bytes, ..example_day() };
+            assert_eq!(day.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Text(String::from("6,1"))));
+        }
+        let open = "0,1\n1,1";
+        assert_eq!(example_day().parse_and_solve(open, Part::Two), Err(AocError::new("the path is never blocked")));
+    }
+}
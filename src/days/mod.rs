@@ -0,0 +1,46 @@
+use crate::solution::DynSolution;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_09;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+// only compiled for the tests, so the template `aoc new` scaffolds from is checked against the Solution trait
+#[cfg(test)]
+mod template;
+
+pub fn registry() -> Vec<(u8, Box<dyn DynSolution>)> {
+    vec![
+        (1, Box::new(day_01::Day01)),
+        (2, Box::new(day_02::Day02)),
+        (3, Box::new(day_03::Day03)),
+        (4, Box::new(day_04::Day04)),
+        (5, Box::new(day_05::Day05)),
+        (6, Box::new(day_06::Day06)),
+        (7, Box::new(day_07::Day07)),
+        (9, Box::new(day_09::Day09)),
+        (11, Box::new(day_11::Day11::default())),
+        (12, Box::new(day_12::Day12)),
+        (13, Box::new(day_13::Day13::default())),
+        (14, Box::new(day_14::Day14::default())),
+        (15, Box::new(day_15::Day15)),
+        (16, Box::new(day_16::Day16::default())),
+        (17, Box::new(day_17::Day17::default())),
+        (18, Box::new(day_18::Day18::default())),
+    ]
+}
+
+pub fn get(day: u8) -> Option<Box<dyn DynSolution>> {
+    registry().into_iter().find(|(d, _)| *d == day).map(|(_, solution)| solution)
+}
@@ -0,0 +1,45 @@
+// Template for new days, used by `aoc new <day>`: `XX` is replaced by the day number, and this comment is dropped.
+
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Vec<String>;
+
+    const PART_1_MESSAGE: &'static str = "Total";
+    const PART_2_MESSAGE: &'static str = "Total";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(0u64.into())
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(0u64.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    const EXAMPLE: &str = include_str!("examples/day_XX.txt");
+
+    // the placeholders are what the template solution gives for an empty example - replace them with the answers
+    // to the example from the puzzle description
+    #[test]
+    fn part_1_example() {
+        assert_eq!(DayXX.parse_and_solve(EXAMPLE, Part::One), Ok(Answer::Number(0)));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(DayXX.parse_and_solve(EXAMPLE, Part::Two), Ok(Answer::Number(0)));
+    }
+}
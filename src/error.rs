@@ -0,0 +1,98 @@
+use std::fmt::{Display, Formatter};
+
+// Line and column are 1-based, column and length are counted in characters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+    pub line_text: String,
+}
+
+impl Location {
+    // `span` must be a slice of `input` (e.g. obtained via `lines()` or `split()`), otherwise it can't be located
+    pub fn find(input: &str, span: &str) -> Option<Self> {
+        let start = (span.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+        if start + span.len() > input.len() { return None; }
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        Some(Self {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            len: span.lines().next().map_or(0, |l| l.chars().count()).max(1),
+            line_text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into(), location: None }
+    }
+
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        Self { message: message.into(), location: Location::find(input, span) }
+    }
+
+    // multi-line rendering with the offending input underlined, for the runner
+    pub fn render(&self) -> String {
+        let Some(loc) = &self.location else { return format!("error: {}", self.message); };
+        let gutter = " ".repeat(loc.line.to_string().len());
+        format!(
+            "error: {}\n{gutter}--> line {}, column {}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            self.message, loc.line, loc.column, loc.line, loc.line_text,
+            " ".repeat(loc.column - 1), "^".repeat(loc.len),
+        )
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(loc) => write!(f, "{} (line {}, column {})", self.message, loc.line, loc.column),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<String> for AocError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_span() {
+        let input = "1 2 3\n4 x 6\n";
+        let bad = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        let error = AocError::at(input, bad, "invalid number");
+        assert_eq!(error.location, Some(Location { line: 2, column: 3, len: 1, line_text: String::from("4 x 6") }));
+        assert_eq!(error.to_string(), "invalid number (line 2, column 3)");
+        assert_eq!(error.render(), "error: invalid number\n --> line 2, column 3\n  |\n2 | 4 x 6\n  |   ^");
+    }
+
+    #[test]
+    fn span_outside_input() {
+        let error = AocError::at("1 2 3", "x", "invalid number");
+        assert_eq!(error.location, None);
+        assert_eq!(error.render(), "error: invalid number");
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::ops::Range;
+use itertools::Itertools;
+use crate::math;
+
+// Small deterministic PRNG (SplitMix64), so generated inputs only depend on the seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // (the modulo bias is negligible for the small ranges used here)
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        range.start + self.below(range.len())
+    }
+
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+type Generator = fn(&mut Rng, usize) -> String;
+
+// (day, size used if none is given, generator) - what the size means differs per day
+const GENERATORS: [(u8, usize, Generator); 16] = [
+    (1, 1000, location_lists),
+    (2, 1000, reports),
+    (3, 6, corrupted_memory),
+    (4, 140, word_search),
+    (5, 200, page_ordering),
+    (6, 130, guard_map),
+    (7, 850, calibration_equations),
+    (9, 19999, disk_map),
+    (11, 8, stones),
+    (12, 140, garden_plots),
+    (13, 320, claw_machines),
+    (14, 500, robots),
+    (15, 50, warehouse),
+    (16, 141, reindeer_maze),
+    (17, 16, chronospatial_computer),
+    (18, 3450, falling_bytes),
+];
+
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Option<String> {
+    let (_, default_size, generator) = GENERATORS.iter().find(|(d, _, _)| *d == day)?;
+    Some(generator(&mut Rng::new(seed), size.unwrap_or(*default_size)))
+}
+
+fn grid_to_string(grid: &[Vec<char>]) -> String {
+    grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+// size: number of lines
+fn location_lists(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{}   {}\n", rng.range(10000..100000), rng.range(10000..100000))).collect()
+}
+
+// size: number of reports, most of which are close to being safe
+fn reports(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let direction = if rng.chance(1, 2) { 1 } else { -1 };
+        let mut level = rng.range(10..90) as isize;
+        let levels = (0..rng.range(5..9)).map(|_| {
+            let step = if rng.chance(1, 10) { rng.range(0..6) as isize - 1 } else { rng.range(1..4) as isize };
+            level = (level + direction * step).clamp(1, 99);
+            level
+        }).join(" ");
+        levels + "\n"
+    }).collect()
+}
+
+// size: number of lines
+fn corrupted_memory(rng: &mut Rng, size: usize) -> String {
+    let noise: &[&str] = &["mul", "(", ")", ",", "[", "]", "'", "!", "@", "^", "%", "+", "why", "where", "from", "select", "don", "do", " "];
+    (0..size).map(|_| {
+        let mut line = String::new();
+        for _ in 0..200 {
+            match rng.below(10) {
+                0 | 1 => write!(line, "mul({},{})", rng.range(1..1000), rng.range(1..1000)).unwrap(),
+                2 => line.push_str(if rng.chance(1, 2) { "do()" } else { "don't()" }),
+                3 => write!(line, "mul({},{}]", rng.range(1..1000), rng.range(1..1000)).unwrap(),
+                _ => line.push_str(rng.choose::<&str>(noise)),
+            }
+        }
+        line + "\n"
+    }).collect()
+}
+
+// size: width and height
+fn word_search(rng: &mut Rng, size: usize) -> String {
+    let grid = (0..size).map(|_| (0..size).map(|_| *rng.choose(&['X', 'M', 'A', 'S'])).collect()).collect_vec();
+    grid_to_string(&grid)
+}
+
+// size: number of updates - the rules order every pair of pages, like in the puzzle input
+fn page_ordering(rng: &mut Rng, size: usize) -> String {
+    let mut pages = (10..100).collect_vec();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let mut rules = pages.iter().tuple_combinations().map(|(a, b)| format!("{a}|{b}\n")).collect_vec();
+    rng.shuffle(&mut rules);
+    let updates = (0..size).map(|_| {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(rng.range(2..13) * 2 + 1);
+        // keep roughly half of the updates in the right order
+        if rng.chance(1, 2) { update.sort_by_key(|p| pages.iter().position(|q| q == p)); }
+        update.iter().join(",") + "\n"
+    }).collect::<String>();
+    rules.concat() + "\n" + &updates
+}
+
+// Whether the guard walks off the map, rather than getting stuck in a loop
+fn guard_leaves(grid: &[Vec<char>], start: (usize, usize)) -> bool {
+    let directions: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let (mut position, mut direction) = (start, 0);
+    let mut visited = HashSet::new();
+    while visited.insert((position, direction)) {
+        let (dr, dc) = directions[direction];
+        let (Some(r), Some(c)) = (position.0.checked_add_signed(dr), position.1.checked_add_signed(dc)) else { return true; };
+        match grid.get(r).and_then(|row| row.get(c)) {
+            None => return true,
+            Some('#') => direction = (direction + 1) % 4,
+            Some(_) => position = (r, c),
+        }
+    }
+    false
+}
+
+// size: width and height - maps in which the guard gets stuck are rejected, as part 1 can't be solved for them
+fn guard_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    loop {
+        let mut grid = (0..size).map(|_| (0..size).map(|_| if rng.chance(1, 12) { '#' } else { '.' }).collect_vec()).collect_vec();
+        let start = (rng.below(size), rng.below(size));
+        grid[start.0][start.1] = '^';
+        if guard_leaves(&grid, start) {
+            return grid_to_string(&grid);
+        }
+    }
+}
+
+// size: number of equations, about half of which can be made true
+fn calibration_equations(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let numbers = (0..rng.range(2..9)).map(|_| rng.range(1..100) as u64).collect_vec();
+        let mut total = numbers[0];
+        for &n in &numbers[1..] {
+            total = match rng.below(3) {
+                0 => total + n,
+                1 => total * n,
+                _ => math::concat(total, n).unwrap(),
+            };
+        }
+        if rng.chance(1, 2) { total += 1; }
+        format!("{total}: {}\n", numbers.iter().join(" "))
+    }).collect()
+}
+
+// size: number of digits, alternating between files and free space
+fn disk_map(rng: &mut Rng, size: usize) -> String {
+    let digits = (0..size.max(1) | 1).map(|i| if i % 2 == 0 { rng.range(1..10) } else { rng.range(0..10) }).join("");
+    digits + "\n"
+}
+
+// size: number of stones
+fn stones(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| rng.below(1_000_000)).join(" ") + "\n"
+}
+
+// size: width and height - plots mostly continue a neighbouring region, so regions have irregular shapes
+fn garden_plots(rng: &mut Rng, size: usize) -> String {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+    for r in 0..size {
+        let mut row = Vec::new();
+        for c in 0..size {
+            let plant = match rng.below(10) {
+                0..4 if r > 0 => grid[r - 1][c],
+                0..8 if c > 0 => row[c - 1],
+                _ => char::from(b'A' + rng.below(26) as u8),
+            };
+            row.push(plant);
+        }
+        grid.push(row);
+    }
+    grid_to_string(&grid)
+}
+
+// size: number of machines, three quarters of which can win the prize
+fn claw_machines(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let a = (rng.range(10..100), rng.range(10..100));
+        let b = (rng.range(10..100), rng.range(10..100));
+        let (n_a, n_b) = (rng.range(0..101), rng.range(0..101));
+        let mut prize = (a.0 * n_a + b.0 * n_b, a.1 * n_a + b.1 * n_b);
+        if rng.chance(1, 4) { prize.0 += rng.range(1..10); }
+        format!("Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n", a.0, a.1, b.0, b.1, prize.0, prize.1)
+    }).join("\n")
+}
+
+// size: number of robots, in an area of the default size
+fn robots(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let (v_x, v_y) = (rng.range(0..201) as isize - 100, rng.range(0..207) as isize - 103);
+        format!("p={},{} v={v_x},{v_y}\n", rng.below(101), rng.below(103))
+    }).collect()
+}
+
+// size: width and height of the warehouse, followed by 20 moves per tile
+fn warehouse(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+    let mut grid = (0..size).map(|r| (0..size).map(|c| {
+        if r == 0 || c == 0 || r == size - 1 || c == size - 1 || rng.chance(1, 20) { '#' }
+        else if rng.chance(1, 4) { 'O' }
+        else { '.' }
+    }).collect_vec()).collect_vec();
+    grid[rng.range(1..size - 1)][rng.range(1..size - 1)] = '@';
+    let moves = (0..20 * size * size).map(|_| *rng.choose(&['^', '>', 'v', '<'])).collect_vec();
+    grid_to_string(&grid) + "\n" + &moves.chunks(70).map(|line| line.iter().collect::<String>() + "\n").collect::<String>()
+}
+
+// size: width and height (rounded up to an odd number) - a maze with some loops, start bottom left and end top right
+fn reindeer_maze(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mut grid = vec![vec!['#'; size]; size];
+    // depth-first carving of passages between the cells at odd coordinates
+    let mut stack: Vec<(usize, usize)> = vec![(size - 2, 1)];
+    grid[size - 2][1] = '.';
+    while let Some(&(r, c)) = stack.last() {
+        let neighbours = [(r.wrapping_sub(2), c), (r + 2, c), (r, c.wrapping_sub(2)), (r, c + 2)].into_iter()
+            .filter(|&(nr, nc)| nr < size - 1 && nc < size - 1 && grid[nr][nc] == '#')
+            .collect_vec();
+        if neighbours.is_empty() {
+            stack.pop();
+        } else {
+            let (nr, nc) = *rng.choose(&neighbours);
+            grid[(r + nr) / 2][(c + nc) / 2] = '.';
+            grid[nr][nc] = '.';
+            stack.push((nr, nc));
+        }
+    }
+    for _ in 0..size {
+        let (r, c) = (rng.range(1..size - 1), rng.range(1..size - 1));
+        if (r + c) % 2 == 1 { grid[r][c] = '.'; }
+    }
+    grid[size - 2][1] = 'S';
+    grid[1][size - 2] = 'E';
+    grid_to_string(&grid)
+}
+
+// size: number of outputs (at most 16), using the program part 2 is written for
+fn chronospatial_computer(rng: &mut Rng, size: usize) -> String {
+    let bits = 3 * size.clamp(1, 16) as u32;
+    let a = (rng.next_u64() >> (64 - bits)) | (1 << (bits - 1));
+    format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,1,7,5,1,5,4,3,5,5,0,3,3,0\n")
+}
+
+// size: number of bytes, in a memory space of the default size
+fn falling_bytes(rng: &mut Rng, size: usize) -> String {
+    let mut coords = (0..71).cartesian_product(0..71).filter(|&c| c != (0, 0) && c != (70, 70)).collect_vec();
+    rng.shuffle(&mut coords);
+    coords.iter().take(size).map(|(x, y)| format!("{x},{y}\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::solution::Part;
+
+    #[test]
+    fn reproducible() {
+        assert_eq!(generate(16, 7, Some(21)), generate(16, 7, Some(21)));
+        assert_ne!(generate(16, 7, Some(21)), generate(16, 8, Some(21)));
+        assert_eq!(generate(8, 7, None), None);
+    }
+
+    #[test]
+    fn inputs_are_parsed() {
+        for (day, solution) in days::registry() {
+            let input = generate(day, 1, Some(9)).unwrap_or_else(|| panic!("no generator for day {day}"));
+            assert!(solution.parse_input(&input).is_ok(), "day {day}: generated input not accepted");
+        }
+    }
+
+    #[test]
+    fn day_16_variants_agree() {
+        let solution = days::get(16).unwrap();
+        for seed in 0..5 {
+            let maze = solution.parse_input(&generate(16, seed, Some(15)).unwrap()).unwrap();
+            assert_eq!(
+                solution.solve_variant(maze.as_ref(), Part::One, "dijkstra"),
+                solution.solve(maze.as_ref(), Part::One),
+            );
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::str::FromStr;
+use crate::error::AocError;
+use crate::parsing::parse_at;
+
+// Edges given one per line as `a<separator>b`, e.g. `47|53` or `kh-tc`
+pub fn parse_edges<N: FromStr>(input: &str, span: &str, separator: &str) -> Result<Vec<(N, N)>, AocError> {
+    span.lines().map(|line| {
+        let (a, b) = line.split_once(separator).ok_or_else(|| AocError::at(input, line, format!("expected `a{separator}b`")))?;
+        Ok((parse_at(input, a.trim())?, parse_at(input, b.trim())?))
+    }).collect()
+}
+
+// A directed graph stored as adjacency lists - undirected graphs have every edge in both directions.
+// Nodes are kept in the order they were first added, which all algorithms follow to give reproducible results.
+#[derive(Debug, Clone)]
+pub struct Graph<N: Clone + Eq + Hash> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Self { nodes: Vec::new(), indices: HashMap::new(), edges: Vec::new() }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn directed(edges: impl IntoIterator<Item=(N, N)>) -> Self {
+        let mut graph = Self::new();
+        edges.into_iter().for_each(|(a, b)| graph.add_edge(a, b));
+        graph
+    }
+
+    pub fn undirected(edges: impl IntoIterator<Item=(N, N)>) -> Self {
+        let mut graph = Self::new();
+        edges.into_iter().for_each(|(a, b)| graph.add_undirected_edge(a, b));
+        graph
+    }
+
+    // returns the index of the node, which is only added if it isn't in the graph yet
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&i) = self.indices.get(&node) { return i; }
+        self.nodes.push(node.clone());
+        self.edges.push(Vec::new());
+        self.indices.insert(node, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    // adding an edge twice has no effect
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        if !self.edges[from].contains(&to) { self.edges[from].push(to); }
+    }
+
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    pub fn has_edge(&self, from: &N, to: &N) -> bool {
+        match (self.indices.get(from), self.indices.get(to)) {
+            (Some(&from), Some(&to)) => self.edges[from].contains(&to),
+            _ => false,
+        }
+    }
+
+    // the nodes the node has edges to
+    pub fn neighbours(&self, node: &N) -> impl Iterator<Item=&N> {
+        self.indices.get(node).into_iter().flat_map(|&i| self.edges[i].iter().map(|&j| &self.nodes[j]))
+    }
+
+    // The given nodes (those in the graph) and the edges between them
+    pub fn induced_subgraph<'a>(&self, nodes: impl IntoIterator<Item=&'a N>) -> Self where N: 'a {
+        let mut subgraph = Self::new();
+        let kept: Vec<usize> = nodes.into_iter().filter_map(|n| self.indices.get(n).copied()).collect();
+        kept.iter().for_each(|&i| { subgraph.add_node(self.nodes[i].clone()); });
+        for &i in &kept {
+            for &j in &self.edges[i] {
+                if subgraph.contains(&self.nodes[j]) { subgraph.add_edge(self.nodes[i].clone(), self.nodes[j].clone()); }
+            }
+        }
+        subgraph
+    }
+
+    // Kahn's algorithm, taking the earliest added of the available nodes at each point. If the graph has a cycle,
+    // the nodes of one of them are returned instead, in order.
+    pub fn topological_sort(&self) -> Result<Vec<N>, Vec<N>> {
+        let mut in_degrees = vec![0; self.len()];
+        self.edges.iter().flatten().for_each(|&j| in_degrees[j] += 1);
+        let mut available: BinaryHeap<Reverse<usize>> = (0..self.len()).filter(|&i| in_degrees[i] == 0).map(Reverse).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse(i)) = available.pop() {
+            order.push(i);
+            for &j in &self.edges[i] {
+                in_degrees[j] -= 1;
+                if in_degrees[j] == 0 { available.push(Reverse(j)); }
+            }
+        }
+        if order.len() == self.len() {
+            return Ok(order.into_iter().map(|i| self.nodes[i].clone()).collect());
+        }
+        // every node left over has an edge from another one left over, so walking those edges backwards must end
+        // up going round a cycle
+        let mut predecessors = vec![None; self.len()];
+        for (i, edges) in self.edges.iter().enumerate().filter(|&(i, _)| in_degrees[i] > 0) {
+            edges.iter().filter(|&&j| in_degrees[j] > 0).for_each(|&j| predecessors[j] = Some(i));
+        }
+        let mut walk = vec![(0..self.len()).find(|&i| in_degrees[i] > 0).unwrap()];
+        loop {
+            let previous = predecessors[*walk.last().unwrap()].unwrap();
+            if let Some(start) = walk.iter().position(|&i| i == previous) {
+                // starting from the earliest added node of the cycle
+                let mut cycle: Vec<usize> = walk[start..].iter().rev().copied().collect();
+                let first = (0..cycle.len()).min_by_key(|&k| cycle[k]).unwrap();
+                cycle.rotate_left(first);
+                return Err(cycle.into_iter().map(|i| self.nodes[i].clone()).collect());
+            }
+            walk.push(previous);
+        }
+    }
+
+    // Tarjan's algorithm (without recursion, so deep graphs don't overflow the stack). Components come out in
+    // reverse topological order: no edge leads from a component to an earlier one.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let mut indices: Vec<Option<usize>> = vec![None; self.len()];
+        let mut low_links = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+        for root in 0..self.len() {
+            if indices[root].is_some() { continue; }
+            // (node, position of the next edge to follow)
+            let mut calls = vec![(root, 0)];
+            indices[root] = Some(next_index);
+            low_links[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some(&mut (v, ref mut next_edge)) = calls.last_mut() {
+                if let Some(&w) = self.edges[v].get(*next_edge) {
+                    *next_edge += 1;
+                    match indices[w] {
+                        None => {
+                            indices[w] = Some(next_index);
+                            low_links[w] = next_index;
+                            next_index += 1;
+                            stack.push(w);
+                            on_stack[w] = true;
+                            calls.push((w, 0));
+                        }
+                        Some(index) if on_stack[w] => low_links[v] = low_links[v].min(index),
+                        Some(_) => (),
+                    }
+                    continue;
+                }
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low_links[parent] = low_links[parent].min(low_links[v]);
+                }
+                if Some(low_links[v]) == indices[v] {
+                    let mut component = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        component.push(self.nodes[w].clone());
+                        if w == v { break; }
+                    }
+                    component.reverse();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    // Bron–Kerbosch with pivoting, treating two nodes as adjacent if there's an edge between them in either direction
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        let mut adjacent: Vec<HashSet<usize>> = vec![HashSet::new(); self.len()];
+        for (i, edges) in self.edges.iter().enumerate() {
+            for &j in edges.iter().filter(|&&j| j != i) {
+                adjacent[i].insert(j);
+                adjacent[j].insert(i);
+            }
+        }
+        let mut cliques = Vec::new();
+        bron_kerbosch(&adjacent, &mut Vec::new(), (0..self.len()).collect(), HashSet::new(), &mut cliques);
+        cliques.into_iter().map(|mut clique| {
+            clique.sort();
+            clique.into_iter().map(|i| self.nodes[i].clone()).collect()
+        }).collect()
+    }
+}
+
+fn bron_kerbosch(adjacent: &[HashSet<usize>], clique: &mut Vec<usize>, mut candidates: HashSet<usize>, mut excluded: HashSet<usize>, cliques: &mut Vec<Vec<usize>>) {
+    if candidates.is_empty() {
+        if excluded.is_empty() { cliques.push(clique.clone()); }
+        return;
+    }
+    // nodes adjacent to the pivot are found when extending by the pivot (or one of its non-neighbours)
+    let pivot = *candidates.union(&excluded).max_by_key(|&&p| adjacent[p].intersection(&candidates).count()).unwrap();
+    let mut to_try: Vec<usize> = candidates.difference(&adjacent[pivot]).copied().collect();
+    to_try.sort();
+    for node in to_try {
+        clique.push(node);
+        bron_kerbosch(
+            adjacent,
+            clique,
+            candidates.intersection(&adjacent[node]).copied().collect(),
+            excluded.intersection(&adjacent[node]).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let input = "47|53\n97|13\n97 61";
+        assert_eq!(parse_edges::<u32>(input, &input[..11], "|"), Ok(vec![(47, 53), (97, 13)]));
+        assert!(parse_edges::<u32>(input, input, "|").is_err());
+        let graph = Graph::undirected(parse_edges::<String>("kh-tc\nqp-kh", "kh-tc\nqp-kh", "-").unwrap());
+        assert!(graph.has_edge(&String::from("tc"), &String::from("kh")));
+        assert_eq!(graph.neighbours(&String::from("kh")).collect::<Vec<_>>(), ["tc", "qp"]);
+    }
+
+    #[test]
+    fn topological_sort() {
+        let graph = Graph::directed([(5, 3), (1, 3), (3, 2), (1, 5), (4, 2)]);
+        assert_eq!(graph.topological_sort(), Ok(vec![1, 5, 3, 4, 2]));
+        assert_eq!(graph.induced_subgraph(&[2, 5, 4]).topological_sort(), Ok(vec![5, 4, 2]));
+        let cyclic = Graph::directed([(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+        assert_eq!(cyclic.topological_sort(), Err(vec![1, 2, 3]));
+        assert_eq!(Graph::directed([(7, 7)]).topological_sort(), Err(vec![7]));
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        let graph = Graph::directed([('a', 'b'), ('b', 'c'), ('c', 'a'), ('c', 'd'), ('d', 'e'), ('e', 'd'), ('f', 'e')]);
+        assert_eq!(graph.strongly_connected_components(), [vec!['d', 'e'], vec!['a', 'b', 'c'], vec!['f']]);
+        // a long chain, which a recursive implementation would struggle with
+        let chain = Graph::directed((0..100_000).map(|i| (i, i + 1)));
+        assert_eq!(chain.strongly_connected_components().len(), 100_001);
+    }
+
+    #[test]
+    fn maximal_cliques() {
+        let graph = Graph::undirected([(1, 2), (1, 3), (2, 3), (3, 4), (4, 5), (4, 6), (5, 6), (3, 5), (7, 7)]);
+        let mut cliques = graph.maximal_cliques();
+        cliques.sort();
+        assert_eq!(cliques, [vec![1, 2, 3], vec![3, 4, 5], vec![4, 5, 6], vec![7]]);
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+use crate::error::AocError;
+
+// Minimal JSON support, covering what the runner writes and reads back - only non-negative integers are supported as
+// numbers. Objects keep their insertion order, so output stays stable between runs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Number(u64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object(fields: Vec<(&str, Json)>) -> Self {
+        Self::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Self::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Self::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<u64> for Json {
+    fn from(value: u64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<u8> for Json {
+    fn from(value: u8) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        Self::Number(value.try_into().expect("number too large"))
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(value: Vec<T>) -> Self {
+        Self::Array(value.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+fn write_escaped(f: &mut Formatter<'_>, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", u32::from(c))?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Number(n) => write!(f, "{n}"),
+            Self::String(s) => write_escaped(f, s),
+            Self::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 { f.write_char(',')?; }
+                    write!(f, "{item}")?;
+                }
+                f.write_char(']')
+            }
+            Self::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 { f.write_char(',')?; }
+                    write_escaped(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> AocError {
+        let end = self.input[self.position..].chars().next().map_or(self.position, |c| self.position + c.len_utf8());
+        AocError::at(self.input, &self.input[self.position..end], message)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), AocError> {
+        self.skip_whitespace();
+        if self.peek() != Some(c) { return Err(self.error(&format!("expected '{c}'"))); }
+        self.position += c.len_utf8();
+        Ok(())
+    }
+
+    // Parses a comma-separated sequence of items up to `end`, the opening bracket having been consumed already
+    fn sequence<T>(&mut self, end: char, mut item: impl FnMut(&mut Self) -> Result<T, AocError>) -> Result<Vec<T>, AocError> {
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(end) {
+            self.position += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(c) if c == end => {
+                    self.position += 1;
+                    return Ok(items);
+                }
+                _ => return Err(self.error(&format!("expected ',' or '{end}'"))),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, AocError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        let mut chars = self.input[self.position..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.position += i + 1;
+                    return Ok(s);
+                }
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                        let c = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32);
+                        s.push(c.ok_or_else(|| self.error("invalid unicode escape"))?);
+                    }
+                    Some(c @ ('"' | '\\' | '/')) => s.push(c),
+                    _ => return Err(self.error("invalid escape sequence")),
+                },
+                c => s.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn value(&mut self) -> Result<Json, AocError> {
+        self.skip_whitespace();
+        let rest = &self.input[self.position..];
+        match self.peek() {
+            Some('{') => {
+                self.position += 1;
+                self.sequence('}', |p| {
+                    let key = p.string()?;
+                    p.expect(':')?;
+                    Ok((key, p.value()?))
+                }).map(Json::Object)
+            }
+            Some('[') => {
+                self.position += 1;
+                self.sequence(']', Self::value).map(Json::Array)
+            }
+            Some('"') => self.string().map(Json::String),
+            Some('0'..='9') => {
+                let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+                let n = rest[..digits].parse().map_err(|_| self.error("number too large"))?;
+                self.position += digits;
+                Ok(Json::Number(n))
+            }
+            _ if rest.starts_with("null") => {
+                self.position += 4;
+                Ok(Json::Null)
+            }
+            _ => Err(self.error("expected a value")),
+        }
+    }
+}
+
+impl FromStr for Json {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s, position: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position < s.len() { return Err(parser.error("unexpected trailing characters")); }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let value = Json::object(vec![
+            ("day", Json::from(7u8)),
+            ("answer", Json::from("4,6\n\"x\"")),
+            ("error", Json::from(None::<String>)),
+            ("timings", Json::object(vec![("parse_ns", Json::Number(3))])),
+            ("at", Json::from(vec![1usize, 2])),
+        ]);
+        assert_eq!(value.to_string(), r#"{"day":7,"answer":"4,6\n\"x\"","error":null,"timings":{"parse_ns":3},"at":[1,2]}"#);
+    }
+
+    #[test]
+    fn parse() {
+        let value = Json::object(vec![
+            ("rows", Json::from(vec!["#.\"\\", "\u{1}"])),
+            ("empty", Json::Array(Vec::new())),
+            ("none", Json::Null),
+            ("nested", Json::object(vec![("n", Json::Number(42))])),
+        ]);
+        assert_eq!(value.to_string().parse::<Json>(), Ok(value.clone()));
+        assert_eq!(" { \"n\" : [ 1 , 2 ] } ".parse::<Json>().map(|j| j.get("n").cloned()), Ok(Some(Json::from(vec![1usize, 2]))));
+        assert_eq!("{\"n\": -1}".parse::<Json>().map_err(|e| e.location.map(|l| l.column)), Err(Some(7)));
+        assert!("[1, 2".parse::<Json>().is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::str::FromStr;
+use crate::error::AocError;
+use crate::parsing::parse_at;
+use crate::solution::{Answer, Part};
+
+// kept next to aoc.toml rather than under input/, so the answer history is tracked with the code
+pub const LEDGER_FILE: &str = "ledger.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too-high"),
+            Self::TooLow => write!(f, "too-low"),
+            Self::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            _ => Err(format!("invalid verdict: {s} (expected correct, too-high, too-low or wrong)")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: Answer,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerCheck {
+    Unknown,
+    Confirmed,
+    Regression(Answer),
+    KnownWrong,
+    TooHigh(u64),
+    TooLow(u64),
+}
+
+impl LedgerCheck {
+    pub fn is_problem(&self) -> bool {
+        !matches!(self, Self::Unknown | Self::Confirmed)
+    }
+
+    // short identifier for machine-readable output
+    pub fn status(&self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Confirmed => "confirmed",
+            Self::Regression(_) => "regression",
+            Self::KnownWrong => "known-wrong",
+            Self::TooHigh(_) => "too-high",
+            Self::TooLow(_) => "too-low",
+        }
+    }
+}
+
+impl Display for LedgerCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "not in ledger"),
+            Self::Confirmed => write!(f, "confirmed"),
+            Self::Regression(expected) => write!(f, "differs from confirmed answer {expected}"),
+            Self::KnownWrong => write!(f, "already rejected"),
+            Self::TooHigh(bound) => write!(f, "too high - {bound} was already rejected as too high"),
+            Self::TooLow(bound) => write!(f, "too low - {bound} was already rejected as too low"),
+        }
+    }
+}
+
+// One entry per line: `<day> <part> <verdict> <answer>`, separated by tabs.
+// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    pub fn load(path: &str) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AocError::new(format!("unable to read ledger {path}: {e}"))),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), AocError> {
+        fs::write(path, self.to_string()).map_err(|e| AocError::new(format!("unable to write ledger {path}: {e}")))
+    }
+
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, verdict: Verdict, answer: Answer) {
+        // a confirmed answer replaces any previous one
+        if verdict == Verdict::Correct {
+            self.entries.retain(|e| !(e.day == day && e.part == part && e.verdict == Verdict::Correct));
+        }
+        let entry = LedgerEntry { day, part, verdict, answer };
+        if !self.entries.contains(&entry) {
+            self.entries.push(entry);
+        }
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> LedgerCheck {
+        let entries: Vec<&LedgerEntry> = self.entries.iter().filter(|e| e.day == day && e.part == part).collect();
+        if let Some(confirmed) = entries.iter().find(|e| e.verdict == Verdict::Correct) {
+            return if confirmed.answer == *answer { LedgerCheck::Confirmed } else { LedgerCheck::Regression(confirmed.answer.clone()) };
+        }
+        if entries.iter().any(|e| e.answer == *answer) {
+            return LedgerCheck::KnownWrong;
+        }
+        if let Answer::Number(n) = answer {
+            let numeric_bounds = |verdict: Verdict| entries.iter().filter(move |e| e.verdict == verdict).filter_map(|e| match e.answer {
+                Answer::Number(bound) => Some(bound),
+                Answer::Text(_) => None,
+            });
+            if let Some(bound) = numeric_bounds(Verdict::TooHigh).filter(|b| n >= b).min() {
+                return LedgerCheck::TooHigh(bound);
+            }
+            if let Some(bound) = numeric_bounds(Verdict::TooLow).filter(|b| n <= b).max() {
+                return LedgerCheck::TooLow(bound);
+            }
+        }
+        LedgerCheck::Unknown
+    }
+}
+
+impl FromStr for Ledger {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ledger = Self::default();
+        for line in s.lines() {
+            if line.trim().is_empty() || line.starts_with('#') { continue; }
+            let fields: Vec<&str> = line.splitn(4, '\t').collect();
+            if fields.len() != 4 {
+                return Err(AocError::at(s, line, "invalid ledger entry"));
+            }
+            let day = parse_at(s, fields[0])?;
+            let part = fields[1].parse().map_err(|e| AocError::at(s, fields[1], e))?;
+            let verdict = fields[2].parse().map_err(|e| AocError::at(s, fields[2], e))?;
+            let answer = fields[3].parse().map_err(|e| AocError::at(s, fields[3], e))?;
+            ledger.entries.push(LedgerEntry { day, part, verdict, answer });
+        }
+        Ok(ledger)
+    }
+}
+
+impl Display for Ledger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day\tpart\tverdict\tanswer")?;
+        for e in &self.entries {
+            writeln!(f, "{}\t{}\t{}\t{}", e.day, e.part, e.verdict, e.answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(12, Part::Two, Verdict::TooHigh, Answer::Number(5_498_242));
+        ledger.record(12, Part::Two, Verdict::TooLow, Answer::Number(5_389));
+        ledger.record(12, Part::Two, Verdict::Wrong, Answer::Number(800_000));
+        ledger.record(16, Part::One, Verdict::Correct, Answer::Number(111_480));
+        ledger
+    }
+
+    #[test]
+    fn check_confirmed_answer() {
+        let ledger = example_ledger();
+        assert_eq!(ledger.check(16, Part::One, &Answer::Number(111_480)), LedgerCheck::Confirmed);
+        assert_eq!(ledger.check(16, Part::One, &Answer::Number(111_481)), LedgerCheck::Regression(Answer::Number(111_480)));
+    }
+
+    #[test]
+    fn check_rejected_guesses() {
+        let ledger = example_ledger();
+        assert_eq!(ledger.check(12, Part::Two, &Answer::Number(6_000_000)), LedgerCheck::TooHigh(5_498_242));
+        assert_eq!(ledger.check(12, Part::Two, &Answer::Number(5_000)), LedgerCheck::TooLow(5_389));
+        assert_eq!(ledger.check(12, Part::Two, &Answer::Number(800_000)), LedgerCheck::KnownWrong);
+        assert_eq!(ledger.check(12, Part::Two, &Answer::Number(900_000)), LedgerCheck::Unknown);
+        assert_eq!(ledger.check(12, Part::One, &Answer::Number(6_000_000)), LedgerCheck::Unknown);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut ledger = example_ledger();
+        ledger.record(18, Part::Two, Verdict::Correct, Answer::Text(String::from("6,1")));
+        assert_eq!(ledger.to_string().parse::<Ledger>(), Ok(ledger));
+    }
+
+    #[test]
+    fn committed_ledger() {
+        let ledger: Ledger = include_str!("../ledger.txt").parse().unwrap();
+        assert_eq!(ledger.check(12, Part::Two, &Answer::Number(5_389)), LedgerCheck::KnownWrong);
+        assert_eq!(ledger.check(15, Part::Two, &Answer::Number(1_500_000)), LedgerCheck::TooHigh(1_442_998));
+        assert_eq!(ledger.check(16, Part::One, &Answer::Number(111_480)), LedgerCheck::Confirmed);
+        assert_eq!(ledger.check(14, Part::Two, &Answer::Number(7_892)), LedgerCheck::Confirmed);
+        assert_eq!(ledger.to_string(), include_str!("../ledger.txt"));
+    }
+}
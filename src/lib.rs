@@ -1,30 +1,58 @@
+#[cfg(feature = "alloc-stats")]
+pub mod alloc_stats;
+pub mod automaton;
+pub mod cancel;
+pub mod days;
+pub mod error;
+pub mod generate;
+pub mod graph;
+pub mod json;
+pub mod ledger;
+pub mod math;
+pub mod params;
+pub mod parsing;
+pub mod progress;
+pub mod range_set;
+pub mod simulation;
+pub mod solution;
+pub mod trace;
+
 pub mod coord_grid {
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashSet, VecDeque};
     use std::fmt::Debug;
-    use std::fs::File;
-    use std::io::{BufReader, Lines};
+    use strum::IntoEnumIterator;
     use strum_macros::EnumIter;
+    use crate::error::AocError;
 
     #[derive(Debug, Clone)]
-    pub struct Grid<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> {
-        _grid: [[T; GRID_WIDTH]; GRID_HEIGHT],
+    pub struct Grid<T: Copy + Debug + Default + PartialEq> {
+        width: usize,
+        height: usize,
+        _grid: Vec<Vec<T>>,
     }
 
-    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> Grid<GRID_WIDTH, GRID_HEIGHT, T> {
-        pub fn create() -> Self {
-            Self { _grid: [[T::default(); GRID_WIDTH]; GRID_HEIGHT] }
+    #[allow(clippy::result_unit_err)]
+    impl<T: Copy + Debug + Default + PartialEq> Grid<T> {
+        pub fn create(width: usize, height: usize) -> Self {
+            Self { width, height, _grid: vec![vec![T::default(); width]; height] }
+        }
+
+        pub fn width(&self) -> usize {
+            self.width
+        }
+
+        pub fn height(&self) -> usize {
+            self.height
         }
 
         pub fn set_all(&mut self, v: T) {
-            for i in 0..GRID_HEIGHT {
-                for j in 0..GRID_WIDTH {
-                    self._grid[i][j] = v;
-                }
-            }
+            self._grid.iter_mut().for_each(|row| row.fill(v));
         }
 
         pub fn contains_coords(&self, coords: (usize, usize)) -> bool {
             let (i, j) = coords;
-            i < GRID_HEIGHT && j < GRID_WIDTH
+            i < self.height && j < self.width
         }
 
         pub fn contains_coords_signed(&self, coords: (isize, isize)) -> bool {
@@ -51,8 +79,8 @@ pub mod coord_grid {
             }
         }
 
-        pub fn iter_rows(&self) -> impl Iterator<Item=&[T; GRID_WIDTH]> {
-            self._grid.iter()
+        pub fn iter_rows(&self) -> impl Iterator<Item=&[T]> {
+            self._grid.iter().map(|row| row.as_slice())
         }
 
         pub fn iter(&self) -> impl Iterator<Item=&T> {
@@ -60,14 +88,15 @@ pub mod coord_grid {
         }
 
         pub fn iter_coords(&self) -> impl Iterator<Item=(usize, usize)> {
-            (0..GRID_HEIGHT).flat_map(|i| (0..GRID_WIDTH).map(move |j| (i.clone(), j)))
+            let width = self.width;
+            (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
         }
         
         pub fn move_coords(&self, coords: (usize, usize), direction: Direction) -> Result<(usize, usize), ()> {
             match direction {
                 Direction::North => if coords.0 > 0 { Ok((coords.0 - 1, coords.1)) } else { Err(()) },
-                Direction::East => if coords.1 < GRID_WIDTH - 1 { Ok((coords.0, coords.1 + 1)) } else { Err(()) },
-                Direction::South => if coords.0 < GRID_HEIGHT - 1 { Ok((coords.0 + 1, coords.1)) } else { Err(()) },
+                Direction::East => if coords.1 + 1 < self.width { Ok((coords.0, coords.1 + 1)) } else { Err(()) },
+                Direction::South => if coords.0 + 1 < self.height { Ok((coords.0 + 1, coords.1)) } else { Err(()) },
                 Direction::West => if coords.1 > 0 { Ok((coords.0, coords.1 - 1)) } else { Err(()) },
                 Direction::Northeast => self.move_coords(self.move_coords(coords, Direction::North)?, Direction::East),
                 Direction::Southeast => self.move_coords(self.move_coords(coords, Direction::South)?, Direction::East),
@@ -91,45 +120,196 @@ pub mod coord_grid {
         }
 
         pub fn position(&self, needle: T) -> Option<(usize, usize)> {
-            for i in 0..GRID_HEIGHT {
-                for j in 0..GRID_WIDTH {
-                    if self.get((i, j)).unwrap() == needle {
-                        return Some((i, j));
+            self.iter_coords().find(|&coords| self.get(coords).unwrap() == needle)
+        }
+
+        // Number of steps (in the main directions) from the closest source to each tile, `None` for unreachable tiles.
+        // Sources are included even if they aren't passable themselves.
+        pub fn bfs_distances(&self, sources: &[(usize, usize)], passable: impl Fn(T) -> bool) -> Grid<Option<usize>> {
+            let mut distances = Grid::create(self.width, self.height);
+            let mut queue = VecDeque::new();
+            for &source in sources {
+                if distances.set(source, Some(0)).is_ok() { queue.push_back(source); }
+            }
+            while let Some(coords) = queue.pop_front() {
+                let distance = distances.get(coords).unwrap().unwrap();
+                for direction in Direction::main_directions() {
+                    let Ok(neighbour) = self.move_coords(coords, direction) else { continue; };
+                    if distances.get(neighbour).unwrap().is_none() && passable(self.get(neighbour).unwrap()) {
+                        distances.set(neighbour, Some(distance + 1)).unwrap();
+                        queue.push_back(neighbour);
                     }
                 }
             }
-            None
+            distances
         }
 
-        pub fn raw(&self) -> &[[T; GRID_WIDTH]; GRID_HEIGHT] {
+        // Like `bfs_distances`, with `cost` giving the cost of entering a tile, or `None` if it can't be entered
+        pub fn weighted_distances(&self, sources: &[(usize, usize)], cost: impl Fn(T) -> Option<u64>) -> Grid<Option<u64>> {
+            let mut distances = Grid::create(self.width, self.height);
+            let mut queue = BinaryHeap::new();
+            for &source in sources {
+                if distances.set(source, Some(0)).is_ok() { queue.push(Reverse((0, source))); }
+            }
+            while let Some(Reverse((distance, coords))) = queue.pop() {
+                if distances.get(coords).unwrap().is_some_and(|d| d < distance) { continue; }
+                for direction in Direction::main_directions() {
+                    let Ok(neighbour) = self.move_coords(coords, direction) else { continue; };
+                    let Some(cost) = cost(self.get(neighbour).unwrap()) else { continue; };
+                    let new_distance = distance + cost;
+                    if distances.get(neighbour).unwrap().is_none_or(|d| new_distance < d) {
+                        distances.set(neighbour, Some(new_distance)).unwrap();
+                        queue.push(Reverse((new_distance, neighbour)));
+                    }
+                }
+            }
+            distances
+        }
+
+        // All tiles within `radius` steps of `coords` (ignoring what is on them), with their Manhattan distance
+        pub fn within_manhattan(&self, coords: (usize, usize), radius: usize) -> impl Iterator<Item=((usize, usize), usize)> + '_ {
+            let (i, j) = coords;
+            let rows = i.saturating_sub(radius)..=(i + radius).min(self.height.saturating_sub(1));
+            rows.flat_map(move |r| {
+                let remaining = radius - r.abs_diff(i);
+                let columns = j.saturating_sub(remaining)..=(j + remaining).min(self.width.saturating_sub(1));
+                columns.map(move |c| ((r, c), r.abs_diff(i) + c.abs_diff(j)))
+            }).filter(|&(c, _)| self.contains_coords(c))
+        }
+
+        pub fn raw(&self) -> &[Vec<T>] {
             &self._grid
         }
 
-        pub fn raw_mut(&mut self) -> &mut [[T; GRID_WIDTH]; GRID_HEIGHT] {
+        pub fn raw_mut(&mut self) -> &mut [Vec<T>] {
             &mut self._grid
         }
     }
 
-    pub fn file_lines_to_char_grid<const GRID_WIDTH: usize, const GRID_HEIGHT: usize>(lines: Lines<BufReader<File>>) -> Result<Grid<GRID_WIDTH, GRID_HEIGHT, char>, String> {
-        let mut grid = Grid::create();
-        let mut num_lines = 0;
-        let mut line_lengths_valid = true;
-        lines.enumerate().for_each(|(i, l)| {
-            num_lines += 1;
-            let line = l.unwrap();
-            if line.len() != GRID_WIDTH { line_lengths_valid = false }
-            line.chars().enumerate().for_each(|(j, c)| { let _ = grid.set((i, j), c); });
-        });
-        if num_lines != GRID_HEIGHT {
-            Err(format!("incorrect number of lines: found {num_lines}, expected {GRID_HEIGHT}"))
-        } else if ! line_lengths_valid {
-            Err(format!("incorrect line length: expected {GRID_WIDTH} characters"))
-        } else {
-            Ok(grid)
+    #[cfg(feature = "parallel")]
+    impl<T: Copy + Debug + Default + PartialEq + Sync> Grid<T> {
+        pub fn par_iter_rows(&self) -> impl rayon::iter::IndexedParallelIterator<Item=&[T]> {
+            use rayon::prelude::*;
+            self._grid.par_iter().map(|row| row.as_slice())
+        }
+
+        pub fn par_iter_coords(&self) -> impl rayon::iter::ParallelIterator<Item=(usize, usize)> {
+            use rayon::prelude::*;
+            let width = self.width;
+            (0..self.height).into_par_iter().flat_map_iter(move |i| (0..width).map(move |j| (i, j)))
+        }
+    }
+
+    // grid dimensions are taken from the input, which must be rectangular
+    pub fn str_to_grid<T, F>(input: &str, parse_char: F) -> Result<Grid<T>, AocError>
+    where
+        T: Copy + Debug + Default + PartialEq,
+        F: Fn(char) -> Result<T, String>,
+    {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.first().map(|l| l.chars().count()).ok_or("empty grid")?;
+        let mut grid = Grid::create(width, lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(AocError::at(input, line, format!("incorrect line length: expected {width} characters")));
+            }
+            for (j, (pos, c)) in line.char_indices().enumerate() {
+                let value = parse_char(c).map_err(|e| AocError::at(input, &line[pos..pos + c.len_utf8()], e))?;
+                grid.set((i, j), value).unwrap();
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn str_to_char_grid(input: &str) -> Result<Grid<char>, AocError> {
+        str_to_grid(input, Ok)
+    }
+
+    // A position and the direction faced there
+    pub type Pose = ((usize, usize), Direction);
+
+    // Consecutive poses either move one tile (in any direction) or turn on the spot
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub struct Path {
+        poses: Vec<Pose>,
+    }
+
+    impl Path {
+        pub fn new(poses: Vec<Pose>) -> Self {
+            Self { poses }
+        }
+
+        // Faces the direction of each step, starting with `initial` - `None` if the tiles aren't adjacent
+        pub fn from_coords(coords: &[(usize, usize)], initial: Direction) -> Option<Self> {
+            let mut poses = Vec::with_capacity(coords.len());
+            let mut direction = initial;
+            for (i, &c) in coords.iter().enumerate() {
+                if i > 0 { direction = Self::step_direction(coords[i - 1], c)?; }
+                poses.push((c, direction));
+            }
+            Some(Self { poses })
+        }
+
+        fn step_direction(from: (usize, usize), to: (usize, usize)) -> Option<Direction> {
+            let delta = (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize);
+            Direction::iter().find(|d| d.offset() == delta)
+        }
+
+        pub fn poses(&self) -> &[Pose] {
+            &self.poses
+        }
+
+        // number of tiles moved
+        pub fn len(&self) -> usize {
+            self.poses.windows(2).filter(|w| w[0].0 != w[1].0).count()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        // number of 90 degree turns, with a 45 degree turn counting as a whole one
+        pub fn turns(&self) -> usize {
+            self.poses.windows(2).map(|w| {
+                let steps = (0..8).find(|&n| w[0].1.turn_anticlockwise(45 * n) == Ok(w[1].1)).unwrap();
+                steps.min(8 - steps).div_ceil(2)
+            }).sum()
+        }
+
+        pub fn cost(&self, move_cost: u64, turn_cost: u64) -> u64 {
+            self.len() as u64 * move_cost + self.turns() as u64 * turn_cost
+        }
+
+        pub fn cells(&self) -> HashSet<(usize, usize)> {
+            self.poses.iter().map(|&(c, _)| c).collect()
+        }
+
+        // whether the path comes back to a tile it has left before
+        pub fn is_self_intersecting(&self) -> bool {
+            let mut visited = HashSet::new();
+            self.poses.iter().enumerate().any(|(i, &(c, _))| (i == 0 || self.poses[i - 1].0 != c) && !visited.insert(c))
+        }
+
+        // Draws the path over the grid, with an arrow for the direction last faced on each tile
+        pub fn render<T: Copy + Debug + Default + PartialEq>(&self, grid: &Grid<T>, tile_char: impl Fn(T) -> char) -> Vec<String> {
+            let mut rows: Vec<Vec<char>> = grid.iter_rows().map(|row| row.iter().map(|&t| tile_char(t)).collect()).collect();
+            for &((i, j), direction) in &self.poses {
+                if let Some(tile) = rows.get_mut(i).and_then(|row| row.get_mut(j)) {
+                    *tile = match direction {
+                        Direction::North => '^',
+                        Direction::East => '>',
+                        Direction::South => 'v',
+                        Direction::West => '<',
+                        Direction::Northeast | Direction::Southwest => '/',
+                        Direction::Southeast | Direction::Northwest => '\\',
+                    };
+                }
+            }
+            rows.into_iter().map(|row| row.into_iter().collect()).collect()
         }
     }
 
-    #[derive(Debug, PartialEq, Eq, Copy, Clone, EnumIter, Hash)]
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, EnumIter, Hash)]
     pub enum Direction {
         North,
         Northeast,
@@ -181,19 +361,89 @@ pub mod coord_grid {
             }
         }
         
+        // (row, column) change when moving one tile this way
+        pub fn offset(&self) -> (isize, isize) {
+            match self {
+                Self::North => (-1, 0),
+                Self::Northeast => (-1, 1),
+                Self::East => (0, 1),
+                Self::Southeast => (1, 1),
+                Self::South => (1, 0),
+                Self::Southwest => (1, -1),
+                Self::West => (0, -1),
+                Self::Northwest => (-1, -1),
+            }
+        }
+
         pub fn main_directions() -> [Self; 4] {
             [Self::North, Self::East, Self::South, Self::West]
         }
 
         pub fn turn_anticlockwise(&self, degrees: usize) -> Result<Self, &str> {
-            if degrees % 45 != 0 {
+            if !degrees.is_multiple_of(45) {
                 Err("invalid turn angle - must be multiple of 45")
             } else {
                 let num_45deg_turns = degrees / 45;
-                let mut new_dir = self.clone();
+                let mut new_dir = *self;
                 for _ in 0..num_45deg_turns { new_dir = new_dir.turn_45_anticlockwise(); }
                 Ok(new_dir)
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const MAZE: &str = "S.#.\n..#.\n....\n##.E";
+
+        #[test]
+        fn distances() {
+            let grid = str_to_char_grid(MAZE).unwrap();
+            let distances = grid.bfs_distances(&[(0, 0)], |c| c != '#');
+            assert_eq!(distances.get((3, 3)), Ok(Some(6)));
+            assert_eq!(distances.get((0, 3)), Ok(Some(7)));
+            assert_eq!(distances.get((0, 2)), Ok(None));
+            let from_both_ends = grid.bfs_distances(&[(0, 0), (3, 3)], |c| c != '#');
+            assert_eq!(from_both_ends.get((0, 3)), Ok(Some(3)));
+            assert_eq!(from_both_ends.get((2, 1)), Ok(Some(3)));
+            // dots are twice as slow to walk through
+            let weighted = grid.weighted_distances(&[(0, 0)], |c| match c { '#' => None, '.' => Some(2), _ => Some(1) });
+            assert_eq!(weighted.get((3, 3)), Ok(Some(11)));
+            assert_eq!(weighted.get((0, 0)), Ok(Some(0)));
+        }
+
+        #[test]
+        fn path() {
+            let grid = str_to_char_grid(MAZE).unwrap();
+            let coords = [(0, 0), (1, 0), (1, 1), (2, 1), (2, 2), (2, 3), (1, 3), (0, 3)];
+            let path = Path::from_coords(&coords, Direction::East).unwrap();
+            assert_eq!((path.len(), path.turns()), (7, 5));
+            assert_eq!(path.cost(1, 1000), 5007);
+            assert!(!path.is_self_intersecting());
+            assert_eq!(path.cells().len(), 8);
+            assert_eq!(path.render(&grid, |c| c), [">.#^", "v>#^", ".v>>", "##.E"]);
+            // turning on the spot doesn't move, turning around takes two turns
+            let mut poses = path.poses().to_vec();
+            poses.extend([((0, 3), Direction::South), ((1, 3), Direction::South)]);
+            let back = Path::new(poses);
+            assert_eq!((back.len(), back.turns()), (8, 7));
+            assert!(back.is_self_intersecting());
+            assert_eq!(Path::from_coords(&[(0, 0), (2, 0)], Direction::East), None);
+        }
+
+        #[test]
+        fn manhattan_neighbourhood() {
+            let grid: Grid<u8> = Grid::create(5, 4);
+            let mut cells: Vec<_> = grid.within_manhattan((1, 1), 2).collect();
+            cells.sort();
+            assert_eq!(cells.len(), 11);
+            assert_eq!(cells.first(), Some(&((0, 0), 2)));
+            assert!(cells.contains(&((1, 1), 0)));
+            assert!(cells.contains(&((1, 3), 2)));
+            assert!(!cells.iter().any(|&(c, _)| c == (3, 2)));
+            assert_eq!(grid.within_manhattan((3, 4), 0).collect::<Vec<_>>(), [((3, 4), 0)]);
+            assert_eq!(grid.within_manhattan((2, 2), 100).count(), 20);
+        }
+    }
 }
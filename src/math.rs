@@ -0,0 +1,218 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// The unsigned integer types the helpers below work with
+pub trait Unsigned:
+    Copy + Ord + Debug + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Rem<Output=Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn checked_ilog10(self) -> Option<u32>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl Unsigned for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TEN: Self = 10;
+
+            fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+            fn checked_mul(self, other: Self) -> Option<Self> { <$t>::checked_mul(self, other) }
+            fn checked_pow(self, exp: u32) -> Option<Self> { <$t>::checked_pow(self, exp) }
+            fn checked_ilog10(self) -> Option<u32> { <$t>::checked_ilog10(self) }
+        }
+    )*};
+}
+
+impl_unsigned!(u32, u64, u128, usize);
+
+// The signed integer types, for the helpers whose results can be negative
+pub trait Signed:
+    Copy + Ord + Debug + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        }
+    )*};
+}
+
+impl_signed!(i32, i64, i128, isize);
+
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// `None` if the result doesn't fit in `T`
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO { return Some(T::ZERO); }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// (gcd(a, b), x, y) with a * x + b * y = gcd(a, b)
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::ZERO { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+// a * b (mod m), without overflowing even if a * b doesn't fit in `T`
+pub fn mul_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    let (mut a, mut b) = (a % m, b % m);
+    if let Some(product) = a.checked_mul(b) { return product % m; }
+    let two = T::ONE + T::ONE;
+    let mut result = T::ZERO;
+    while b != T::ZERO {
+        if b % two == T::ONE { result = add_mod(result, a, m); }
+        a = add_mod(a, a, m);
+        b = b / two;
+    }
+    result
+}
+
+// (both values must be below m)
+fn add_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+fn sub_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    if a >= b { a - b } else { m - (b - a) }
+}
+
+// x with a * x = 1 (mod m), if a and m are coprime
+pub fn mod_inverse<T: Unsigned>(a: T, m: T) -> Option<T> {
+    if m == T::ONE { return Some(T::ZERO); }
+    // extended Euclid, keeping the coefficients of a reduced modulo m so they never go negative
+    let (mut old_r, mut r) = (a % m, m);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, sub_mod(old_x, mul_mod(q, x, m), m));
+    }
+    (old_r == T::ONE).then_some(old_x)
+}
+
+// Chinese remainder theorem: the smallest x with x = residue (mod modulus) for all congruences, along with the
+// modulus of the solution (the lcm of the moduli). The moduli don't need to be coprime. `None` if there's no
+// solution, or if the combined modulus doesn't fit in `T`.
+pub fn crt<T: Unsigned>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut solution = (T::ZERO, T::ONE);
+    for &(residue, modulus) in congruences {
+        let (x, m) = solution;
+        let g = gcd(m, modulus);
+        let difference = sub_mod(residue % modulus, x % modulus, modulus);
+        if difference % g != T::ZERO { return None; }
+        let reduced_modulus = modulus / g;
+        let t = mul_mod(difference / g, mod_inverse(m / g, reduced_modulus)?, reduced_modulus);
+        let combined_modulus = (m / g).checked_mul(modulus)?;
+        solution = (x.checked_add(m.checked_mul(t)?)? % combined_modulus, combined_modulus);
+    }
+    Some(solution)
+}
+
+// number of decimal digits, with 0 having one
+pub fn digit_count<T: Unsigned>(n: T) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+// `n` without and with its last `digits` digits, e.g. 1234 split at 1 is (123, 4)
+pub fn split_digits<T: Unsigned>(n: T, digits: u32) -> (T, T) {
+    match T::TEN.checked_pow(digits) {
+        Some(divisor) => (n / divisor, n % divisor),
+        None => (T::ZERO, n),
+    }
+}
+
+// the digits of `a` followed by those of `b`, e.g. 12 and 345 give 12345 - `None` if the result doesn't fit in `T`
+pub fn concat<T: Unsigned>(a: T, b: T) -> Option<T> {
+    a.checked_mul(T::TEN.checked_pow(digit_count(b))?)?.checked_add(b)
+}
+
+// whether the decimal digits of `n` end with those of `suffix`
+pub fn ends_with<T: Unsigned>(n: T, suffix: T) -> bool {
+    strip_suffix(n, suffix).is_some()
+}
+
+// `n` without the digits of `suffix` at its end, e.g. 12345 and 45 give 123 - the inverse of `concat`
+pub fn strip_suffix<T: Unsigned>(n: T, suffix: T) -> Option<T> {
+    let (rest, end) = split_digits(n, digit_count(suffix));
+    (end == suffix && n >= suffix).then_some(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(0u64, 7), 7);
+        assert_eq!(lcm(101u64, 103), Some(10403));
+        assert_eq!(lcm(4u32, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(extended_gcd(240i128, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-12i32, 18), (6, 1, 1));
+        assert_eq!(extended_gcd(0i64, -5), (5, 0, -1));
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(mod_inverse(3u64, 11), Some(4));
+        assert_eq!(mod_inverse(6u64, 9), None);
+        assert_eq!(mul_mod(u128::MAX - 1, u128::MAX - 2, u128::MAX), 2);
+        let m = (1u128 << 127) - 1;
+        let inverse = mod_inverse(123_456_789u128, m).unwrap();
+        assert_eq!(mul_mod(inverse, 123_456_789, m), 1);
+    }
+
+    #[test]
+    fn chinese_remainder_theorem() {
+        assert_eq!(crt(&[(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // e.g. a robot at x = 3 (mod 101) and y = 5 (mod 103)
+        assert_eq!(crt(&[(3u64, 101), (5, 103)]), Some((10305, 10403)));
+        assert_eq!(crt(&[(1u64, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1u64, 4), (2, 6)]), None);
+        assert_eq!(crt::<u128>(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(digit_count(0u64), 1);
+        assert_eq!(digit_count(1000u64), 4);
+        assert_eq!(digit_count(u128::MAX), 39);
+        assert_eq!(split_digits(253_000u64, 3), (253, 0));
+        assert_eq!(split_digits(17u64, 40), (0, 17));
+        assert_eq!(concat(15u64, 6), Some(156));
+        assert_eq!(concat(15u64, 0), Some(150));
+        assert_eq!(concat(u64::MAX, 1), None);
+        assert!(ends_with(12_345u64, 45));
+        assert!(ends_with(45u64, 45));
+        assert!(!ends_with(5u64, 45));
+        assert!(!ends_with(12_345u64, 35));
+        assert_eq!(strip_suffix(12_345u128, 345), Some(12));
+        assert_eq!(strip_suffix(12_345u128, 12_345), Some(0));
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::str::FromStr;
+use crate::error::AocError;
+use crate::parsing::parse_at;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+// A named tunable of a solution, with its current value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub value: String,
+    pub description: &'static str,
+}
+
+impl Param {
+    pub fn new(name: &'static str, value: impl Display, description: &'static str) -> Self {
+        Self { name, value: value.to_string(), description }
+    }
+}
+
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, AocError> {
+    value.parse().map_err(|_| AocError::new(format!("invalid value for parameter {name}: {value}")))
+}
+
+pub fn unknown_param(name: &str) -> AocError {
+    AocError::new(format!("unknown parameter: {name}"))
+}
+
+// `name=value`, as given on the command line
+pub fn parse_assignment(assignment: &str) -> Result<(String, String), AocError> {
+    let (name, value) = assignment.split_once('=').ok_or_else(|| AocError::new(format!("expected name=value, got {assignment}")))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+// Parameter values from `aoc.toml`, which may contain one table per day, e.g.:
+//
+//   [day_11]
+//   n_blinks_part_2 = 100
+//
+// Only this subset of TOML is supported: tables named `day_NN`, comments and `name = value` entries with bare or
+// double-quoted values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParamConfig {
+    days: BTreeMap<u8, Vec<(String, String)>>,
+}
+
+impl ParamConfig {
+    pub fn load(path: &str) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AocError::new(format!("unable to read {path}: {e}"))),
+        }
+    }
+
+    pub fn for_day(&self, day: u8) -> &[(String, String)] {
+        self.days.get(&day).map_or(&[], |params| params.as_slice())
+    }
+}
+
+impl FromStr for ParamConfig {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = Self::default();
+        let mut current_day = None;
+        for line in s.lines() {
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('#') { continue; }
+            if let Some(table) = content.strip_prefix('[') {
+                let table = table.strip_suffix(']').ok_or_else(|| AocError::at(s, line, "unterminated table header"))?;
+                let day = table.strip_prefix("day_").ok_or_else(|| AocError::at(s, table, "expected a table named day_NN"))?;
+                current_day = Some(parse_at::<u8>(s, day)?);
+                continue;
+            }
+            let day = current_day.ok_or_else(|| AocError::at(s, line, "parameter outside of a [day_NN] table"))?;
+            let (name, value) = content.split_once('=').ok_or_else(|| AocError::at(s, line, "expected name = value"))?;
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted.strip_suffix('"').ok_or_else(|| AocError::at(s, value, "unterminated string"))?,
+                None => value.split_once('#').map_or(value, |(v, _)| v).trim(),
+            };
+            config.days.entry(day).or_default().push((name.trim().to_string(), value.to_string()));
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config: ParamConfig = "# tunables\n[day_11]\nn_blinks_part_2 = 100  # more blinks\n\n[day_18]\ngrid_size = \"7\"\n".parse().unwrap();
+        assert_eq!(config.for_day(11), [(String::from("n_blinks_part_2"), String::from("100"))]);
+        assert_eq!(config.for_day(18), [(String::from("grid_size"), String::from("7"))]);
+        assert!(config.for_day(1).is_empty());
+    }
+
+    #[test]
+    fn invalid_config() {
+        let error = "n_bytes = 12\n".parse::<ParamConfig>().unwrap_err();
+        assert_eq!(error.location.map(|l| l.line), Some(1));
+        assert!("[day_x]\n".parse::<ParamConfig>().is_err());
+        assert_eq!(parse_assignment("n_blinks=100"), Ok((String::from("n_blinks"), String::from("100"))));
+    }
+}
@@ -0,0 +1,151 @@
+use std::str::FromStr;
+use std::sync::LazyLock;
+use regex::{Captures, Regex};
+use crate::error::AocError;
+
+// All helpers take the complete puzzle input alongside the slice being parsed, so that errors can point at the
+// offending text - `span` must therefore be a slice of `input` (e.g. obtained via `lines()` or `split()`).
+
+static INTEGER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"-?\d+").unwrap());
+
+pub fn parse_at<T: FromStr>(input: &str, span: &str) -> Result<T, AocError> {
+    span.parse().map_err(|_| AocError::at(input, span, format!("unable to parse {span:?}")))
+}
+
+// all (possibly negative) integers in `span`, ignoring everything in between
+pub fn integers<T: FromStr>(input: &str, span: &str) -> Result<Vec<T>, AocError> {
+    INTEGER_REGEX.find_iter(span).map(|m| parse_at(input, m.as_str())).collect()
+}
+
+pub fn separated<T: FromStr>(input: &str, span: &str, separator: &str) -> Result<Vec<T>, AocError> {
+    span.split(separator).map(|s| parse_at(input, s.trim())).collect()
+}
+
+pub fn comma_list<T: FromStr>(input: &str, span: &str) -> Result<Vec<T>, AocError> {
+    separated(input, span, ",")
+}
+
+pub fn space_list<T: FromStr>(input: &str, span: &str) -> Result<Vec<T>, AocError> {
+    span.split_whitespace().map(|s| parse_at(input, s)).collect()
+}
+
+// `key: value value ...`
+pub fn key_values<K: FromStr, V: FromStr>(input: &str, span: &str) -> Result<(K, Vec<V>), AocError> {
+    let (key, values) = span.split_once(':').ok_or_else(|| AocError::at(input, span, "expected `key: values`"))?;
+    Ok((parse_at(input, key.trim())?, space_list(input, values)?))
+}
+
+// whitespace-separated `name=value` pairs, e.g. `p=0,4 v=3,-3`
+pub fn name_value_pairs<'a>(input: &str, span: &'a str) -> Result<Vec<(&'a str, &'a str)>, AocError> {
+    span.split_whitespace()
+        .map(|pair| pair.split_once('=').ok_or_else(|| AocError::at(input, pair, "expected `name=value`")))
+        .collect()
+}
+
+pub fn strip_prefix_at<'a>(input: &str, span: &'a str, prefix: &str) -> Result<&'a str, AocError> {
+    span.strip_prefix(prefix).ok_or_else(|| AocError::at(input, span, format!("expected {prefix:?}")))
+}
+
+// splits `input` into the blocks of lines between blank lines, skipping empty blocks
+pub fn split_sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((start, end)) = current.take() { sections.push(&input[start..end]); }
+        } else {
+            let start = current.map_or(offset, |(start, _)| start);
+            current = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = current { sections.push(&input[start..end]); }
+    sections
+}
+
+pub fn exact_sections<const N: usize>(input: &str) -> Result<[&str; N], AocError> {
+    let sections = split_sections(input);
+    if let Some(extra) = sections.get(N) {
+        return Err(AocError::at(input, extra, format!("expected {N} sections separated by blank lines, found {}", sections.len())));
+    }
+    let n = sections.len();
+    sections.try_into().map_err(|_| AocError::new(format!("expected {N} sections separated by blank lines, found {n}")))
+}
+
+pub trait FromCaptures: Sized {
+    fn from_captures(input: &str, captures: &Captures) -> Result<Self, AocError>;
+}
+
+fn capture_group<T: FromStr>(input: &str, captures: &Captures, i: usize) -> Result<T, AocError> {
+    let group = captures.get(i).ok_or_else(|| AocError::at(input, captures.get(0).unwrap().as_str(), format!("missing group {i}")))?;
+    parse_at(input, group.as_str())
+}
+
+macro_rules! impl_from_captures {
+    ($($t:ident $i:literal),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+) {
+            fn from_captures(input: &str, captures: &Captures) -> Result<Self, AocError> {
+                Ok(($(capture_group::<$t>(input, captures, $i)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(A 1);
+impl_from_captures!(A 1, B 2);
+impl_from_captures!(A 1, B 2, C 3);
+impl_from_captures!(A 1, B 2, C 3, D 4);
+impl_from_captures!(A 1, B 2, C 3, D 4, E 5);
+impl_from_captures!(A 1, B 2, C 3, D 4, E 5, F 6);
+
+// parses the capture groups of the first match of `regex` into a tuple, e.g. `(usize, usize)`
+pub fn captures<T: FromCaptures>(input: &str, regex: &Regex, span: &str) -> Result<T, AocError> {
+    let captures = regex.captures(span).ok_or_else(|| AocError::at(input, span, format!("expected a match for {}", regex.as_str())))?;
+    T::from_captures(input, &captures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_integers() {
+        let input = "p=0,4 v=3,-3";
+        assert_eq!(integers::<i32>(input, input), Ok(vec![0, 4, 3, -3]));
+    }
+
+    #[test]
+    fn parse_lists() {
+        let input = "190: 10 19\n1,2, 3\n7 x";
+        let lines: Vec<&str> = input.lines().collect();
+        assert_eq!(key_values::<u64, u64>(input, lines[0]), Ok((190, vec![10, 19])));
+        assert_eq!(comma_list::<u8>(input, lines[1]), Ok(vec![1, 2, 3]));
+        assert_eq!(space_list::<u8>(input, lines[2]).unwrap_err().location.map(|l| (l.line, l.column)), Some((3, 3)));
+    }
+
+    #[test]
+    fn parse_name_value_pairs() {
+        let input = "p=0,4 v=3,-3";
+        assert_eq!(name_value_pairs(input, input), Ok(vec![("p", "0,4"), ("v", "3,-3")]));
+        assert!(name_value_pairs("p0", "p0").is_err());
+    }
+
+    #[test]
+    fn split_into_sections() {
+        let input = "\n1|2\n3|4\n\n\n5,6\r\n  \r\n7,8\n\n";
+        assert_eq!(split_sections(input), vec!["1|2\n3|4", "5,6", "7,8"]);
+        assert_eq!(exact_sections::<3>(input), Ok(["1|2\n3|4", "5,6", "7,8"]));
+        assert_eq!(exact_sections::<2>(input).unwrap_err().location.map(|l| l.line), Some(8));
+        assert!(exact_sections::<4>(input).is_err());
+    }
+
+    #[test]
+    fn parse_captures() {
+        let input = "Button A: X+94, Y+34";
+        let regex = Regex::new(r"X\+(\d+), Y\+(\d+)").unwrap();
+        assert_eq!(captures::<(usize, usize)>(input, &regex, input), Ok((94, 34)));
+        assert!(captures::<(usize, usize)>("Prize", &regex, "Prize").is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use crate::cancel::{self, CancellationToken};
+
+// All output goes to stderr, so that answers printed by the runner on stdout stay readable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+const BAR_WIDTH: usize = 30;
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        1 => Verbosity::Normal,
+        _ => Verbosity::Verbose,
+    }
+}
+
+pub fn is_enabled(level: Verbosity) -> bool {
+    verbosity() >= level
+}
+
+// Use `format_args!` for messages in hot loops, so nothing is formatted unless the message is shown
+pub fn info(message: impl Display) {
+    if is_enabled(Verbosity::Normal) { eprintln!("{message}"); }
+}
+
+pub fn debug(message: impl Display) {
+    if is_enabled(Verbosity::Verbose) { eprintln!("{message}"); }
+}
+
+// Can be shared between threads, e.g. when iterating in parallel.
+// The progress is also recorded in the current cancellation token, so it can be reported if the solution times out.
+pub struct ProgressBar {
+    label: &'static str,
+    total: usize,
+    current: AtomicUsize,
+    start: Instant,
+    last_drawn: Mutex<Option<Instant>>,
+    visible: bool,
+    token: CancellationToken,
+}
+
+impl ProgressBar {
+    // only drawn at normal verbosity or above, and only if stderr is a terminal
+    pub fn new(label: &'static str, total: usize) -> Self {
+        let visible = is_enabled(Verbosity::Normal) && io::stderr().is_terminal();
+        Self {
+            label, total, current: AtomicUsize::new(0), start: Instant::now(), last_drawn: Mutex::new(None), visible,
+            token: cancel::current(),
+        }
+    }
+
+    pub fn inc(&self) {
+        let current = self.current.fetch_add(1, Ordering::Relaxed) + 1;
+        self.redraw(current);
+    }
+
+    pub fn set(&self, current: usize) {
+        self.current.store(current, Ordering::Relaxed);
+        self.redraw(current);
+    }
+
+    fn redraw(&self, current: usize) {
+        // other threads can skip drawing while one of them holds the lock
+        let Ok(mut last_drawn) = self.last_drawn.try_lock() else { return; };
+        if last_drawn.is_none_or(|t| t.elapsed() >= REDRAW_INTERVAL) {
+            self.token.set_progress(format_args!("{} {current}/{}", self.label, self.total));
+            if self.visible { self.draw(current.min(self.total)); }
+            *last_drawn = Some(Instant::now());
+        }
+    }
+
+    fn eta(&self, current: usize) -> Option<Duration> {
+        if current == 0 { return None; }
+        let per_step = self.start.elapsed() / current.try_into().ok()?;
+        Some(per_step * (self.total - current).try_into().ok()?)
+    }
+
+    fn draw(&self, current: usize) {
+        let filled = (BAR_WIDTH * current).checked_div(self.total).unwrap_or(BAR_WIDTH);
+        let eta = self.eta(current).map(|d| format!("{:.1}s", d.as_secs_f64())).unwrap_or(String::from("?"));
+        eprint!(
+            "\r{} [{}{}] {current}/{} ETA {eta}   ",
+            self.label, "#".repeat(filled), " ".repeat(BAR_WIDTH - filled), self.total,
+        );
+        let _ = io::stderr().flush();
+    }
+}
+
+impl Drop for ProgressBar {
+    fn drop(&mut self) {
+        if self.visible && self.last_drawn.get_mut().is_ok_and(|t| t.is_some()) {
+            eprint!("\r\x1b[2K");
+            let _ = io::stderr().flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_bar_eta() {
+        let bar = ProgressBar::new("test", 4);
+        assert_eq!(bar.eta(0), None);
+        bar.inc();
+        bar.inc();
+        assert_eq!(bar.current.load(Ordering::Relaxed), 2);
+        assert_eq!(bar.eta(4), Some(Duration::ZERO));
+    }
+}
@@ -0,0 +1,335 @@
+use std::fmt::{self, Debug};
+use std::ops::Range;
+use crate::generate::Rng;
+use crate::math::Unsigned;
+
+// A set of values stored as disjoint, non-adjacent half-open ranges, which are merged and split as values are
+// inserted and removed. The ranges are kept in a treap ordered by start, with every node also knowing the length
+// of the longest range below it, so that all operations take logarithmic time (expected).
+#[derive(Clone)]
+pub struct RangeSet<T: Unsigned> {
+    root: Link<T>,
+    // node priorities only need to look random
+    rng: Rng,
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Clone)]
+struct Node<T: Unsigned> {
+    start: T,
+    end: T,
+    priority: u64,
+    left: Link<T>,
+    right: Link<T>,
+    // of the subtree rooted here
+    count: usize,
+    total_len: T,
+    max_len: T,
+}
+
+impl<T: Unsigned> Node<T> {
+    fn update(&mut self) {
+        let len = self.end - self.start;
+        let (mut count, mut total_len, mut max_len) = (1, len, len);
+        for child in [&self.left, &self.right].into_iter().flatten() {
+            count += child.count;
+            total_len = total_len + child.total_len;
+            max_len = max_len.max(child.max_len);
+        }
+        (self.count, self.total_len, self.max_len) = (count, total_len, max_len);
+    }
+}
+
+// Splits the tree into the nodes whose start satisfies `goes_left` (which must hold for a prefix of the ranges) and
+// the others
+fn split<T: Unsigned>(link: Link<T>, goes_left: impl Fn(T) -> bool + Copy) -> (Link<T>, Link<T>) {
+    let Some(mut node) = link else { return (None, None); };
+    if goes_left(node.start) {
+        let (left, right) = split(node.right.take(), goes_left);
+        node.right = left;
+        node.update();
+        (Some(node), right)
+    } else {
+        let (left, right) = split(node.left.take(), goes_left);
+        node.left = right;
+        node.update();
+        (left, Some(node))
+    }
+}
+
+// (all ranges of `a` must come before those of `b`)
+fn merge<T: Unsigned>(a: Link<T>, b: Link<T>) -> Link<T> {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                a.right = merge(a.right.take(), Some(b));
+                a.update();
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take());
+                b.update();
+                Some(b)
+            }
+        }
+    }
+}
+
+fn pop_last<T: Unsigned>(link: Link<T>) -> (Link<T>, Option<Range<T>>) {
+    let Some(mut node) = link else { return (None, None); };
+    if node.right.is_some() {
+        let (right, last) = pop_last(node.right.take());
+        node.right = right;
+        node.update();
+        (Some(node), last)
+    } else {
+        (node.left.take(), Some(node.start..node.end))
+    }
+}
+
+fn first_fit<T: Unsigned>(link: &Link<T>, size: T) -> Option<&Node<T>> {
+    let node = link.as_deref().filter(|n| n.max_len >= size)?;
+    first_fit(&node.left, size)
+        .or_else(|| (node.end - node.start >= size).then_some(node))
+        .or_else(|| first_fit(&node.right, size))
+}
+
+impl<T: Unsigned> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { root: None, rng: Rng::new(0) }
+    }
+
+    fn node(&mut self, range: Range<T>) -> Link<T> {
+        let mut node = Node {
+            start: range.start, end: range.end, priority: self.rng.next_u64(), left: None, right: None,
+            count: 0, total_len: T::ZERO, max_len: T::ZERO,
+        };
+        node.update();
+        Some(Box::new(node))
+    }
+
+    // Adds the values of the range, merging it with the ranges it overlaps or touches
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() { return; }
+        let (mut start, mut end) = (range.start, range.end);
+        let (left, rest) = split(self.root.take(), |s| s < start);
+        let (mut left, previous) = pop_last(left);
+        if let Some(previous) = previous {
+            if previous.end >= start {
+                start = previous.start;
+                end = end.max(previous.end);
+            } else {
+                left = merge(left, self.node(previous));
+            }
+        }
+        let (overlapping, right) = split(rest, |s| s <= end);
+        if let (_, Some(last)) = pop_last(overlapping) { end = end.max(last.end); }
+        let node = self.node(start..end);
+        self.root = merge(merge(left, node), right);
+    }
+
+    // Removes the values of the range, shortening or splitting the ranges it overlaps
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() { return; }
+        let (start, end) = (range.start, range.end);
+        let (left, rest) = split(self.root.take(), |s| s < start);
+        let (mut left, previous) = pop_last(left);
+        let (inside, right) = split(rest, |s| s < end);
+        let (_, last_inside) = pop_last(inside);
+        let mut pieces = Vec::new();
+        if let Some(previous) = previous {
+            pieces.push(previous.start..previous.end.min(start));
+            if previous.end > end { pieces.push(end..previous.end); }
+        }
+        if let Some(last_inside) = last_inside.filter(|r| r.end > end) { pieces.push(end..last_inside.end); }
+        for piece in pieces.into_iter().filter(|p| !p.is_empty()) {
+            let node = self.node(piece);
+            left = merge(left, node);
+        }
+        self.root = merge(left, right);
+    }
+
+    // Adds all values of the other set
+    pub fn merge(&mut self, other: &RangeSet<T>) {
+        other.iter().for_each(|r| self.insert(r));
+    }
+
+    // Moves the values from `at` onwards into a new set, splitting the range containing `at` if there is one
+    pub fn split_off(&mut self, at: T) -> RangeSet<T> {
+        let (left, right) = split(self.root.take(), |s| s < at);
+        let (mut left, last) = pop_last(left);
+        let mut other = RangeSet { root: right, rng: Rng::new(self.rng.next_u64()) };
+        if let Some(last) = last {
+            if last.end > at {
+                let node = other.node(at..last.end);
+                other.root = merge(node, other.root.take());
+            }
+            let node = self.node(last.start..last.end.min(at));
+            left = merge(left, node);
+        }
+        self.root = left;
+        other
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.range_containing(value).is_some()
+    }
+
+    pub fn range_containing(&self, value: T) -> Option<Range<T>> {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            if value < node.start {
+                link = &node.left;
+            } else if value < node.end {
+                return Some(node.start..node.end);
+            } else {
+                link = &node.right;
+            }
+        }
+        None
+    }
+
+    // The first range holding at least `size` values which all lie before `before`, e.g. the leftmost gap a file
+    // could be moved to when the set holds the free space
+    pub fn first_fit(&self, size: T, before: T) -> Option<Range<T>> {
+        let node = first_fit(&self.root, size)?;
+        // ranges further right start even later, so can't fit either if this one doesn't
+        (node.start.checked_add(size)? <= before).then_some(node.start..node.end)
+    }
+
+    // number of ranges
+    pub fn len(&self) -> usize {
+        self.root.as_ref().map_or(0, |n| n.count)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    // number of values
+    pub fn total_len(&self) -> T {
+        self.root.as_ref().map_or(T::ZERO, |n| n.total_len)
+    }
+
+    // the ranges in order
+    pub fn iter(&self) -> impl Iterator<Item=Range<T>> + '_ {
+        let mut stack = Vec::new();
+        let mut link = &self.root;
+        std::iter::from_fn(move || {
+            while let Some(node) = link {
+                stack.push(node);
+                link = &node.left;
+            }
+            let node = stack.pop()?;
+            link = &node.right;
+            Some(node.start..node.end)
+        })
+    }
+}
+
+impl<T: Unsigned> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Unsigned> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item=Range<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        ranges.into_iter().for_each(|r| set.insert(r));
+        set
+    }
+}
+
+impl<T: Unsigned> PartialEq for RangeSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Unsigned> Eq for RangeSet<T> {}
+
+impl<T: Unsigned> Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    // (as pairs, since clippy mistakes single ranges in arrays for typos)
+    fn ranges(set: &RangeSet<u64>) -> Vec<(u64, u64)> {
+        set.iter().map(|r| (r.start, r.end)).collect()
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set: RangeSet<u64> = [10..20, 30..40, 20..25, 50..50].into_iter().collect();
+        assert_eq!(ranges(&set), [(10, 25), (30, 40)]);
+        set.insert(24..31);
+        assert_eq!(ranges(&set), [(10, 40)]);
+        set.remove(15..17);
+        set.remove(39..100);
+        set.remove(0..11);
+        assert_eq!(ranges(&set), [(11, 15), (17, 39)]);
+        assert_eq!((set.len(), set.total_len()), (2, 26));
+        assert!(set.contains(11) && set.contains(38) && !set.contains(15) && !set.contains(39));
+        assert_eq!(set.range_containing(20), Some(17..39));
+        set.remove(0..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn split_and_merge() {
+        let mut set: RangeSet<u64> = [0..10, 20..30].into_iter().collect();
+        let right = set.split_off(25);
+        assert_eq!((ranges(&set), ranges(&right)), (vec![(0, 10), (20, 25)], vec![(25, 30)]));
+        let far_right = set.split_off(15);
+        assert_eq!((ranges(&set), ranges(&far_right)), (vec![(0, 10)], vec![(20, 25)]));
+        set.merge(&right);
+        set.merge(&std::iter::once(8..26).collect());
+        assert_eq!(ranges(&set), [(0, 30)]);
+    }
+
+    #[test]
+    fn first_fit() {
+        let set: RangeSet<u64> = [2..4, 8..9, 12..15, 18..21].into_iter().collect();
+        assert_eq!(set.first_fit(1, 100), Some(2..4));
+        assert_eq!(set.first_fit(3, 100), Some(12..15));
+        assert_eq!(set.first_fit(3, 15), Some(12..15));
+        assert_eq!(set.first_fit(3, 14), None);
+        assert_eq!(set.first_fit(4, 100), None);
+    }
+
+    // random operations, checked against a set of single values
+    #[test]
+    fn matches_naive() {
+        let mut rng = Rng::new(7);
+        let mut set = RangeSet::new();
+        let mut values = [false; 200];
+        for _ in 0..2000 {
+            let start = rng.below(200) as u64;
+            let end = (start + rng.below(20) as u64).min(200);
+            let insert = rng.chance(1, 2);
+            if insert { set.insert(start..end); } else { set.remove(start..end); }
+            (start..end).for_each(|v| values[v as usize] = insert);
+            let expected = values.iter().enumerate().fold(Vec::<(u64, u64)>::new(), |mut acc, (v, &present)| {
+                let v = v as u64;
+                match acc.last_mut() {
+                    Some(last) if present && last.1 == v => last.1 += 1,
+                    _ if present => acc.push((v, v + 1)),
+                    _ => (),
+                }
+                acc
+            });
+            assert_eq!(ranges(&set), expected);
+            assert_eq!(set.total_len(), values.iter().filter(|&&v| v).count() as u64);
+            assert_eq!(set.len(), expected.len());
+        }
+    }
+}
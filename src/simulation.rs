@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use crate::cancel;
+use crate::error::AocError;
+
+// A system that evolves in discrete steps, e.g. a guard walking through a lab or robots moving around an area.
+// Two states with the same key are expected to evolve the same way.
+pub trait Simulation: Clone {
+    type Key: Eq + Hash + Clone;
+
+    fn step(&mut self);
+
+    fn state_key(&self) -> Self::Key;
+
+    // a finished simulation isn't stepped any further
+    fn is_done(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    // number of steps before the first state that repeats
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // The smallest number of steps leading to the same state as `n` steps
+    pub fn equivalent_steps(&self, n: usize) -> usize {
+        if n < self.start { n } else { self.start + (n - self.start) % self.period }
+    }
+}
+
+// Steps until the simulation is done or `n` steps have been made, returning the number of steps made
+pub fn run_n<S: Simulation>(simulation: &mut S, n: usize) -> Result<usize, AocError> {
+    let token = cancel::current();
+    for i in 0..n {
+        token.check()?;
+        if simulation.is_done() { return Ok(i); }
+        simulation.step();
+    }
+    Ok(n)
+}
+
+// Steps until `predicate` holds, returning the number of steps made, or `None` if the simulation finished or
+// `max_steps` were made first
+pub fn run_until<S: Simulation>(simulation: &mut S, max_steps: usize, mut predicate: impl FnMut(&S) -> bool) -> Result<Option<usize>, AocError> {
+    let token = cancel::current();
+    for i in 0..=max_steps {
+        token.check()?;
+        if predicate(simulation) { return Ok(Some(i)); }
+        if i == max_steps || simulation.is_done() { break; }
+        simulation.step();
+    }
+    Ok(None)
+}
+
+// Brent's algorithm, which only keeps two states at a time - `None` if the simulation finishes instead
+pub fn find_cycle<S: Simulation>(simulation: &S) -> Result<Option<Cycle>, AocError> {
+    let token = cancel::current();
+    // a finished state can't be part of a cycle, whatever its key
+    let advance = |state: &mut S| -> Result<bool, AocError> {
+        token.check()?;
+        state.step();
+        Ok(!state.is_done())
+    };
+    // find the period by letting the hare run ahead of the tortoise, which teleports to it at powers of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = simulation.clone();
+    let mut hare = simulation.clone();
+    if hare.is_done() || !advance(&mut hare)? { return Ok(None); }
+    while tortoise.state_key() != hare.state_key() {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        if !advance(&mut hare)? { return Ok(None); }
+        period += 1;
+    }
+    // then the start, with the hare a full period ahead
+    let mut tortoise = simulation.clone();
+    let mut hare = simulation.clone();
+    for _ in 0..period { advance(&mut hare)?; }
+    let mut start = 0;
+    while tortoise.state_key() != hare.state_key() {
+        advance(&mut tortoise)?;
+        advance(&mut hare)?;
+        start += 1;
+    }
+    Ok(Some(Cycle { start, period }))
+}
+
+// Brings the simulation to the state after `n` steps (or the point where it finishes), skipping whole cycles
+// as soon as a state repeats. Returns the cycle if one was found within those steps.
+pub fn fast_forward<S: Simulation>(simulation: &mut S, n: usize) -> Result<Option<Cycle>, AocError> {
+    let token = cancel::current();
+    let mut seen = HashMap::new();
+    for i in 0..n {
+        token.check()?;
+        if simulation.is_done() { return Ok(None); }
+        if let Some(start) = seen.insert(simulation.state_key(), i) {
+            let cycle = Cycle { start, period: i - start };
+            run_n(simulation, cycle.equivalent_steps(n) - start)?;
+            return Ok(Some(cycle));
+        }
+        simulation.step();
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // x -> x * x + 1 (mod m), which eventually repeats, finishing once it reaches `end`
+    #[derive(Clone)]
+    struct Sequence {
+        value: u64,
+        modulus: u64,
+        end: Option<u64>,
+    }
+
+    impl Simulation for Sequence {
+        type Key = u64;
+
+        fn step(&mut self) {
+            self.value = (self.value * self.value + 1) % self.modulus;
+        }
+
+        fn state_key(&self) -> u64 {
+            self.value
+        }
+
+        fn is_done(&self) -> bool {
+            self.end == Some(self.value)
+        }
+    }
+
+    fn naive_states(sequence: &Sequence, n: usize) -> Vec<u64> {
+        let mut sequence = sequence.clone();
+        (0..n).map(|_| { let v = sequence.value; sequence.step(); v }).collect()
+    }
+
+    #[test]
+    fn cycles() {
+        for (value, modulus) in [(0, 255), (3, 1000), (2, 9973), (7, 1)] {
+            let sequence = Sequence { value, modulus, end: None };
+            let cycle = find_cycle(&sequence).unwrap().unwrap();
+            let states = naive_states(&sequence, cycle.start + cycle.period + 1);
+            assert_eq!(states[cycle.start], states[cycle.start + cycle.period]);
+            assert!(!states[..cycle.start + cycle.period].iter().enumerate().any(|(i, s)| states[..i].contains(s)));
+            for n in [0, 5, 1000, 123_456] {
+                let mut fast = sequence.clone();
+                fast_forward(&mut fast, n).unwrap();
+                let expected = naive_states(&sequence, cycle.equivalent_steps(n) + 1)[cycle.equivalent_steps(n)];
+                assert_eq!(fast.value, expected);
+            }
+        }
+    }
+
+    // finishes in the same state as the step before, which mustn't be mistaken for a cycle
+    #[derive(Clone)]
+    struct Walk(u32);
+
+    impl Simulation for Walk {
+        type Key = u32;
+
+        fn step(&mut self) {
+            self.0 += 1;
+        }
+
+        fn state_key(&self) -> u32 {
+            self.0.min(3)
+        }
+
+        fn is_done(&self) -> bool {
+            self.0 > 3
+        }
+    }
+
+    #[test]
+    fn finishing() {
+        assert_eq!(find_cycle(&Walk(0)), Ok(None));
+        assert_eq!(fast_forward(&mut Walk(0), 10), Ok(None));
+        let sequence = Sequence { value: 3, modulus: 1000, end: Some(202) };
+        assert_eq!(find_cycle(&sequence), Ok(None));
+        let mut s = sequence.clone();
+        assert_eq!(run_n(&mut s, 100), Ok(3));
+        let mut s = sequence.clone();
+        assert_eq!(run_until(&mut s, 100, |s| s.value == 10), Ok(Some(1)));
+        assert_eq!(run_until(&mut s, 100, |s| s.value == 11), Ok(None));
+    }
+}
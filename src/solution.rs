@@ -0,0 +1,208 @@
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use crate::error::AocError;
+use crate::params::{unknown_param, Param};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+// anything that is not a plain number is treated as a text answer
+impl FromStr for Answer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Err(String::from("empty answer"))
+        } else {
+            Ok(s.parse().map(Self::Number).unwrap_or_else(|_| Self::Text(s.to_string())))
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+// (only fails on platforms where usize is wider than u64)
+impl TryFrom<usize> for Answer {
+    type Error = AocError;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u64::try_from(value).map(Self::Number).map_err(|_| AocError::new(format!("answer too large: {value}")))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn both() -> [Self; 2] {
+        [Self::One, Self::Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("invalid part: {s}")),
+        }
+    }
+}
+
+// name of the implementation given by `part1` and `part2`
+pub const DEFAULT_VARIANT: &str = "default";
+
+// An alternative implementation of one part, which should give the same answer as the default one
+pub struct Variant<S: Solution + ?Sized> {
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: fn(&S, &S::Input) -> Result<Answer, AocError>,
+}
+
+pub trait Solution {
+    type Input: 'static;
+
+    const PART_1_MESSAGE: &'static str;
+    const PART_2_MESSAGE: &'static str = "Not implemented";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, AocError>;
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        Err(AocError::new("part 2 not implemented"))
+    }
+
+    // tunables that can be set from `aoc.toml` or the command line, with their current values
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), AocError> {
+        Err(unknown_param(name))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        Vec::new()
+    }
+}
+
+// Object-safe counterpart of `Solution`, so that days with different input types can share a registry
+pub trait DynSolution: Send + Sync {
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, AocError>;
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>, AocError> {
+        self.parse_input(&read_input(reader)?)
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<Answer, AocError>;
+
+    fn output_message(&self, part: Part) -> &'static str;
+
+    fn list_params(&self) -> Vec<Param>;
+
+    // names of all implementations of a part, starting with `DEFAULT_VARIANT`
+    fn variant_names(&self, part: Part) -> Vec<&'static str>;
+
+    fn solve_variant(&self, input: &dyn Any, part: Part, variant: &str) -> Result<Answer, AocError>;
+
+    fn apply_param(&mut self, name: &str, value: &str) -> Result<(), AocError>;
+
+    fn parse_and_solve(&self, input: &str, part: Part) -> Result<Answer, AocError> {
+        self.solve(self.parse_input(input)?.as_ref(), part)
+    }
+}
+
+impl<S: Solution + Send + Sync> DynSolution for S {
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<Answer, AocError> {
+        let input = input.downcast_ref::<S::Input>().ok_or("input parsed by a different solution")?;
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    fn output_message(&self, part: Part) -> &'static str {
+        match part {
+            Part::One => S::PART_1_MESSAGE,
+            Part::Two => S::PART_2_MESSAGE,
+        }
+    }
+
+    fn list_params(&self) -> Vec<Param> {
+        self.params()
+    }
+
+    fn variant_names(&self, part: Part) -> Vec<&'static str> {
+        let variants = self.variants().into_iter().filter(|v| v.part == part).map(|v| v.name);
+        [DEFAULT_VARIANT].into_iter().chain(variants).collect()
+    }
+
+    fn solve_variant(&self, input: &dyn Any, part: Part, variant: &str) -> Result<Answer, AocError> {
+        if variant == DEFAULT_VARIANT {
+            return self.solve(input, part);
+        }
+        let v = self.variants().into_iter().find(|v| v.part == part && v.name == variant)
+            .ok_or_else(|| format!("unknown variant for part {part}: {variant}"))?;
+        let input = input.downcast_ref::<S::Input>().ok_or("input parsed by a different solution")?;
+        (v.solve)(self, input)
+    }
+
+    fn apply_param(&mut self, name: &str, value: &str) -> Result<(), AocError> {
+        self.set_param(name, value)
+    }
+}
+
+pub fn read_input<R: BufRead + ?Sized>(reader: &mut R) -> Result<String, AocError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input).map_err(|e| format!("unable to read input: {e}"))?;
+    Ok(input)
+}
@@ -0,0 +1,202 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use strum::IntoEnumIterator;
+use crate::coord_grid::Direction;
+use crate::error::AocError;
+use crate::json::Json;
+
+// (row, column), as used by `Grid`
+pub type Coords = (usize, usize);
+
+// Events emitted by simulations, e.g. to be exported with `aoc run <day> --trace <file>` and viewed with `aoc replay`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    // the full state of the grid, one string per row
+    Grid { rows: Vec<String> },
+    // the tile at `from` is moved to `to`, leaving an empty tile behind
+    Move { entity: String, from: Coords, to: Coords },
+    // a move that couldn't be made
+    Blocked { entity: String, at: Coords, direction: Direction },
+    Turn { entity: String, at: Coords, direction: Direction },
+    // all positions of entities that can share a tile, replacing those from earlier steps
+    Positions { entity: String, at: Vec<Coords> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    pub step: usize,
+    pub event: Event,
+}
+
+#[derive(Default)]
+struct Recorder {
+    step: usize,
+    events: Vec<TraceEvent>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+pub fn is_enabled() -> bool {
+    RECORDER.with_borrow(|recorder| recorder.is_some())
+}
+
+// The event is only created while recording, so tracing costs next to nothing otherwise
+pub fn record(event: impl FnOnce() -> Event) {
+    RECORDER.with_borrow_mut(|recorder| {
+        if let Some(recorder) = recorder {
+            recorder.events.push(TraceEvent { step: recorder.step, event: event() });
+        }
+    });
+}
+
+// Starts the next step of the simulation - events recorded before the first step describe the initial state
+pub fn step() {
+    RECORDER.with_borrow_mut(|recorder| {
+        if let Some(recorder) = recorder { recorder.step += 1; }
+    });
+}
+
+// Records the events emitted on this thread while running `f`
+pub fn with_recording<T>(f: impl FnOnce() -> T) -> (T, Vec<TraceEvent>) {
+    let previous = RECORDER.replace(Some(Recorder::default()));
+    let result = f();
+    let recorder = RECORDER.replace(previous).unwrap_or_default();
+    (result, recorder.events)
+}
+
+pub fn grid_rows<T: Copy>(rows: impl Iterator<Item=impl AsRef<[T]>>, tile_char: impl Fn(T) -> char) -> Vec<String> {
+    rows.map(|row| row.as_ref().iter().map(|&t| tile_char(t)).collect()).collect()
+}
+
+fn coords_json(coords: Coords) -> Json {
+    Json::from(vec![coords.0, coords.1])
+}
+
+impl TraceEvent {
+    pub fn to_json(&self) -> Json {
+        let mut fields = vec![("step", Json::from(self.step))];
+        let (kind, mut details) = match &self.event {
+            Event::Grid { rows } => ("grid", vec![("rows", Json::from(rows.clone()))]),
+            Event::Move { entity, from, to } => (
+                "move", vec![("entity", Json::from(entity.as_str())), ("from", coords_json(*from)), ("to", coords_json(*to))],
+            ),
+            Event::Blocked { entity, at, direction } => (
+                "blocked", vec![("entity", Json::from(entity.as_str())), ("at", coords_json(*at)), ("direction", Json::from(format!("{direction:?}")))],
+            ),
+            Event::Turn { entity, at, direction } => (
+                "turn", vec![("entity", Json::from(entity.as_str())), ("at", coords_json(*at)), ("direction", Json::from(format!("{direction:?}")))],
+            ),
+            Event::Positions { entity, at } => (
+                "positions", vec![("entity", Json::from(entity.as_str())), ("at", Json::Array(at.iter().map(|&c| coords_json(c)).collect()))],
+            ),
+        };
+        fields.push(("event", Json::from(kind)));
+        fields.append(&mut details);
+        Json::object(fields)
+    }
+
+    pub fn from_json(json: &Json) -> Option<Self> {
+        let string = |key| Some(json.get(key)?.as_str()?.to_string());
+        let coords_of = |value: &Json| match value.as_array()? {
+            [r, c] => Some((r.as_u64()?.try_into().ok()?, c.as_u64()?.try_into().ok()?)),
+            _ => None,
+        };
+        let coords = |key| coords_of(json.get(key)?);
+        let direction = || {
+            let name = json.get("direction")?.as_str()?;
+            Direction::iter().find(|d| format!("{d:?}") == name)
+        };
+        let event = match json.get("event")?.as_str()? {
+            "grid" => Event::Grid { rows: json.get("rows")?.as_array()?.iter().map(|r| Some(r.as_str()?.to_string())).collect::<Option<_>>()? },
+            "move" => Event::Move { entity: string("entity")?, from: coords("from")?, to: coords("to")? },
+            "blocked" => Event::Blocked { entity: string("entity")?, at: coords("at")?, direction: direction()? },
+            "turn" => Event::Turn { entity: string("entity")?, at: coords("at")?, direction: direction()? },
+            "positions" => Event::Positions { entity: string("entity")?, at: json.get("at")?.as_array()?.iter().map(coords_of).collect::<Option<_>>()? },
+            _ => return None,
+        };
+        Some(Self { step: json.get("step")?.as_u64()?.try_into().ok()?, event })
+    }
+}
+
+fn direction_char(direction: Direction) -> char {
+    match direction {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+        _ => '*',
+    }
+}
+
+fn tile_mut(grid: &mut [Vec<char>], (r, c): Coords) -> Result<&mut char, AocError> {
+    grid.get_mut(r).and_then(|row| row.get_mut(c)).ok_or_else(|| AocError::new(format!("coordinates outside of the grid: ({r}, {c})")))
+}
+
+// Renders the grid as it was after `step`, starting from the last full grid recorded up to then.
+// Entities sharing tiles (`Positions`) are shown as the number of them on each tile, like in the puzzle descriptions.
+pub fn replay(events: &[TraceEvent], step: usize) -> Result<Vec<String>, AocError> {
+    let events: Vec<&TraceEvent> = events.iter().filter(|e| e.step <= step).collect();
+    let start = events.iter().rposition(|e| matches!(e.event, Event::Grid { .. }))
+        .ok_or_else(|| AocError::new(format!("no grid recorded up to step {step}")))?;
+    let mut grid: Vec<Vec<char>> = Vec::new();
+    let mut positions = BTreeMap::new();
+    for e in &events[start..] {
+        match &e.event {
+            Event::Grid { rows } => grid = rows.iter().map(|r| r.chars().collect()).collect(),
+            Event::Move { from, to, .. } => {
+                let tile = std::mem::replace(tile_mut(&mut grid, *from)?, '.');
+                *tile_mut(&mut grid, *to)? = tile;
+            }
+            Event::Turn { at, direction, .. } => *tile_mut(&mut grid, *at)? = direction_char(*direction),
+            Event::Blocked { .. } => (),
+            Event::Positions { entity, at } => { positions.insert(entity, at); }
+        }
+    }
+    for at in positions.values() {
+        let mut counts: BTreeMap<Coords, u32> = BTreeMap::new();
+        at.iter().for_each(|&c| *counts.entry(c).or_default() += 1);
+        for (c, n) in counts {
+            *tile_mut(&mut grid, c)? = char::from_digit(n.min(9), 10).unwrap();
+        }
+    }
+    Ok(grid.iter().map(|row| row.iter().collect()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simulate() {
+        record(|| Event::Grid { rows: vec![String::from("@O.#"), String::from("....")] });
+        step();
+        record(|| Event::Move { entity: String::from("box"), from: (0, 1), to: (0, 2) });
+        record(|| Event::Move { entity: String::from("robot"), from: (0, 0), to: (0, 1) });
+        step();
+        record(|| Event::Blocked { entity: String::from("robot"), at: (0, 1), direction: Direction::East });
+        step();
+        record(|| Event::Positions { entity: String::from("robot"), at: vec![(1, 0), (1, 0), (1, 3)] });
+    }
+
+    #[test]
+    fn record_and_replay() {
+        simulate();
+        assert!(!is_enabled());
+        let ((), events) = with_recording(simulate);
+        assert_eq!(events.len(), 5);
+        assert_eq!(events[4].step, 3);
+        assert_eq!(replay(&events, 0), Ok(vec![String::from("@O.#"), String::from("....")]));
+        assert_eq!(replay(&events, 2), Ok(vec![String::from(".@O#"), String::from("....")]));
+        assert_eq!(replay(&events, 3), Ok(vec![String::from(".@O#"), String::from("2..1")]));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let ((), events) = with_recording(simulate);
+        for e in events {
+            let json: Json = e.to_json().to_string().parse().unwrap();
+            assert_eq!(TraceEvent::from_json(&json), Some(e));
+        }
+    }
+}